@@ -0,0 +1,315 @@
+//! Adobe Swatch Exchange (.ase) import and export.
+use crate::math;
+use crate::{Color, Family, Lab, Match, Rgb};
+use std::io::{self, Read, Write};
+use std::string::String;
+use std::vec::Vec;
+
+const SIGNATURE: &[u8; 4] = b"ASEF";
+const VERSION: (u16, u16) = (1, 0);
+
+const BLOCK_GROUP_START: u16 = 0xC001;
+const BLOCK_GROUP_END: u16 = 0xC002;
+const BLOCK_COLOR: u16 = 0x0001;
+
+const COLOR_TYPE_GLOBAL: u16 = 0;
+
+/// Writes `colors` as an ASE file.
+///
+/// Colors are grouped by [`Family`] (in order of first appearance, named after
+/// [`Family::display_name()`]) and each swatch is named "{code} {name}" (e.g., "BV04 Blue Berry").
+pub fn write<W: Write>(mut writer: W, colors: &[Color]) -> io::Result<()> {
+    let mut families: Vec<Family> = Vec::new();
+    for color in colors {
+        if !families.contains(&color.family) {
+            families.push(color.family);
+        }
+    }
+
+    let block_count = colors.len() + families.len() * 2;
+    writer.write_all(SIGNATURE)?;
+    writer.write_all(&VERSION.0.to_be_bytes())?;
+    writer.write_all(&VERSION.1.to_be_bytes())?;
+    writer.write_all(&u32_len(block_count)?.to_be_bytes())?;
+
+    for family in families {
+        let mut name = Vec::new();
+        write_name(&mut name, family.display_name())?;
+        write_block(&mut writer, BLOCK_GROUP_START, &name)?;
+
+        for color in colors.iter().filter(|c| c.family == family) {
+            let mut body = Vec::new();
+            write_name(&mut body, &std::format!("{} {}", color.code, color.name))?;
+            body.extend_from_slice(b"RGB ");
+            for c in [color.rgb.r, color.rgb.g, color.rgb.b] {
+                body.extend_from_slice(&(f32::from(c) / 255.0).to_be_bytes());
+            }
+            body.extend_from_slice(&COLOR_TYPE_GLOBAL.to_be_bytes());
+            write_block(&mut writer, BLOCK_COLOR, &body)?;
+        }
+
+        write_block(&mut writer, BLOCK_GROUP_END, &[])?;
+    }
+    Ok(())
+}
+
+/// Swatch read from an ASE file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Swatch {
+    pub name: String,
+
+    /// Name of the group containing this swatch, if any.
+    pub group: Option<String>,
+
+    /// Color of this swatch converted to sRGB.
+    pub rgb: Rgb,
+
+    /// The Copic color nearest to [`Swatch::rgb`].
+    pub nearest: Match<'static>,
+}
+
+/// Reads the swatches in an ASE file and maps each of them to the nearest Copic color.
+///
+/// RGB, Gray, CMYK (naively converted) and LAB swatches are supported.
+pub fn read<R: Read>(mut reader: R) -> Result<Vec<Swatch>, Error> {
+    let mut signature = [0; 4];
+    reader.read_exact(&mut signature)?;
+    if &signature != SIGNATURE {
+        return Err(Error::InvalidSignature);
+    }
+    let major = read_u16(&mut reader)?;
+    let _minor = read_u16(&mut reader)?;
+    if major != VERSION.0 {
+        return Err(Error::UnsupportedVersion(major));
+    }
+    let block_count = read_u32(&mut reader)?;
+
+    let mut swatches = Vec::new();
+    let mut group = None;
+    for _ in 0..block_count {
+        let block_type = read_u16(&mut reader)?;
+        let len = read_u32(&mut reader)?;
+        let mut body = Vec::new();
+        reader
+            .by_ref()
+            .take(u64::from(len))
+            .read_to_end(&mut body)?;
+        if body.len() != len as usize {
+            return Err(Error::Malformed);
+        }
+
+        let mut body = body.as_slice();
+        match block_type {
+            BLOCK_GROUP_START => group = Some(read_name(&mut body)?),
+            BLOCK_GROUP_END => group = None,
+            BLOCK_COLOR => {
+                let name = read_name(&mut body)?;
+                let rgb = read_color(&mut body)?;
+                swatches.push(Swatch {
+                    name,
+                    group: group.clone(),
+                    rgb,
+                    nearest: crate::nearest(rgb),
+                });
+            }
+            _ => {}
+        }
+    }
+    Ok(swatches)
+}
+
+/// Error returned by [`read()`].
+#[derive(Debug)]
+pub enum Error {
+    /// I/O error.
+    Io(io::Error),
+
+    /// The input does not start with the "ASEF" signature.
+    InvalidSignature,
+
+    /// The major version of the file is not supported.
+    UnsupportedVersion(u16),
+
+    /// A swatch uses a color model other than RGB, Gray, CMYK and LAB.
+    UnsupportedColorModel([u8; 4]),
+
+    /// The file structure is broken.
+    Malformed,
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "I/O error: {e}"),
+            Self::InvalidSignature => write!(f, "not an ASE file"),
+            Self::UnsupportedVersion(v) => write!(f, "unsupported ASE version: {v}"),
+            Self::UnsupportedColorModel(m) => {
+                write!(
+                    f,
+                    "unsupported color model: {:?}",
+                    String::from_utf8_lossy(m)
+                )
+            }
+            Self::Malformed => write!(f, "malformed ASE file"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        if e.kind() == io::ErrorKind::UnexpectedEof {
+            Self::Malformed
+        } else {
+            Self::Io(e)
+        }
+    }
+}
+
+fn write_block<W: Write>(writer: &mut W, block_type: u16, body: &[u8]) -> io::Result<()> {
+    writer.write_all(&block_type.to_be_bytes())?;
+    writer.write_all(&u32_len(body.len())?.to_be_bytes())?;
+    writer.write_all(body)
+}
+
+fn write_name(buf: &mut Vec<u8>, name: &str) -> io::Result<()> {
+    let units: Vec<u16> = name.encode_utf16().chain([0]).collect();
+    let len = u16::try_from(units.len())
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "too long swatch name"))?;
+    buf.extend_from_slice(&len.to_be_bytes());
+    for unit in units {
+        buf.extend_from_slice(&unit.to_be_bytes());
+    }
+    Ok(())
+}
+
+fn u32_len(len: usize) -> io::Result<u32> {
+    u32::try_from(len)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "too large ASE block"))
+}
+
+fn read_name(body: &mut &[u8]) -> Result<String, Error> {
+    let len = read_u16(body)?;
+    let units = (0..len)
+        .map(|_| read_u16(body))
+        .collect::<Result<Vec<_>, _>>()?;
+    let units = units.strip_suffix(&[0]).unwrap_or(&units);
+    String::from_utf16(units).map_err(|_| Error::Malformed)
+}
+
+fn read_color(body: &mut &[u8]) -> Result<Rgb, Error> {
+    let mut model = [0; 4];
+    body.read_exact(&mut model)?;
+    let mut next = || read_u32(body).map(f32::from_bits);
+    let rgb = match &model {
+        b"RGB " => Rgb::new(to_u8(next()?), to_u8(next()?), to_u8(next()?)),
+        b"Gray" => {
+            let v = to_u8(next()?);
+            Rgb::new(v, v, v)
+        }
+        b"CMYK" => {
+            let (c, m, y, k) = (next()?, next()?, next()?, next()?);
+            Rgb::new(
+                to_u8((1.0 - c) * (1.0 - k)),
+                to_u8((1.0 - m) * (1.0 - k)),
+                to_u8((1.0 - y) * (1.0 - k)),
+            )
+        }
+        b"LAB " => Lab::new(next()? * 100.0, next()?, next()?).to_rgb(),
+        _ => return Err(Error::UnsupportedColorModel(model)),
+    };
+    Ok(rgb)
+}
+
+fn to_u8(v: f32) -> u8 {
+    math::roundf(v.clamp(0.0, 1.0) * 255.0) as u8
+}
+
+fn read_u16<R: Read>(reader: &mut R) -> io::Result<u16> {
+    let mut buf = [0; 2];
+    reader.read_exact(&mut buf)?;
+    Ok(u16::from_be_bytes(buf))
+}
+
+fn read_u32<R: Read>(reader: &mut R) -> io::Result<u32> {
+    let mut buf = [0; 4];
+    reader.read_exact(&mut buf)?;
+    Ok(u32::from_be_bytes(buf))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ALL_COLORS, COLOR_BV04};
+    use std::format;
+
+    #[test]
+    fn round_trip() {
+        let mut buf = Vec::new();
+        write(&mut buf, &ALL_COLORS).unwrap();
+        let swatches = read(buf.as_slice()).unwrap();
+
+        assert_eq!(swatches.len(), ALL_COLORS.len());
+        for color in &ALL_COLORS {
+            let name = format!("{} {}", color.code, color.name);
+            let swatch = swatches.iter().find(|s| s.name == name).unwrap();
+            assert_eq!(swatch.group.as_deref(), Some(color.family.display_name()));
+            assert_eq!(swatch.rgb, color.rgb);
+            assert_eq!(swatch.nearest.color.rgb, color.rgb);
+            assert_eq!(swatch.nearest.distance, 0);
+        }
+    }
+
+    #[test]
+    fn read_color_models() {
+        fn swatch(model: &[u8; 4], values: &[f32]) -> Vec<u8> {
+            let mut body = Vec::new();
+            write_name(&mut body, "test").unwrap();
+            body.extend_from_slice(model);
+            for v in values {
+                body.extend_from_slice(&v.to_be_bytes());
+            }
+            body.extend_from_slice(&COLOR_TYPE_GLOBAL.to_be_bytes());
+
+            let mut file = Vec::new();
+            file.extend_from_slice(SIGNATURE);
+            file.extend_from_slice(&VERSION.0.to_be_bytes());
+            file.extend_from_slice(&VERSION.1.to_be_bytes());
+            file.extend_from_slice(&1u32.to_be_bytes());
+            write_block(&mut file, BLOCK_COLOR, &body).unwrap();
+            file
+        }
+        let read_rgb = |model: &[u8; 4], values: &[f32]| {
+            let swatches = read(swatch(model, values).as_slice()).unwrap();
+            assert_eq!(swatches.len(), 1);
+            assert_eq!(swatches[0].group, None);
+            swatches[0].rgb
+        };
+
+        assert_eq!(read_rgb(b"RGB ", &[1.0, 0.0, 0.5]), Rgb::new(255, 0, 128));
+        assert_eq!(read_rgb(b"Gray", &[0.2]), Rgb::new(51, 51, 51));
+        assert_eq!(
+            read_rgb(b"CMYK", &[1.0, 0.0, 0.5, 0.2]),
+            Rgb::new(0, 204, 102)
+        );
+
+        let lab = COLOR_BV04.lab();
+        assert_eq!(
+            read_rgb(b"LAB ", &[lab.l / 100.0, lab.a, lab.b]),
+            COLOR_BV04.rgb
+        );
+
+        assert!(matches!(
+            read(swatch(b"HSB ", &[0.0, 0.0, 0.0]).as_slice()),
+            Err(Error::UnsupportedColorModel(m)) if &m == b"HSB "
+        ));
+    }
+}
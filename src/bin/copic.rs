@@ -0,0 +1,289 @@
+//! `copic` command-line tool for looking up, searching and converting Copic colors.
+use copic_colors::{delta_e, Color, Family, Group, Query, Rgb, Value};
+use serde_json::json;
+use std::io::{self, IsTerminal, Write};
+use std::ops::Bound;
+use std::process::ExitCode;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+const USAGE: &str = "\
+Usage: copic [--json] <COMMAND>
+
+Commands:
+  show <CODE>       Show the details of a color (e.g., `copic show BV04`)
+  search <TEXT>     Search colors by name or code (e.g., `copic search shadow pink`)
+  nearest <RGB>     Find the colors nearest to an RGB value (e.g., `copic nearest '#92a4ce'`)
+  list [OPTIONS]    List colors
+      --family <FAMILY>   Filter by family (e.g., `Earth`, `BlueViolet`)
+      --group <RANGE>     Filter by group (e.g., `S0`, `S0..S2`, `S3..`)
+      --value <RANGE>     Filter by value (e.g., `B3`, `B0..B3`, `..B0`)
+
+Options:
+  --json            Print the result as JSON
+  -h, --help        Print this help";
+
+const FAMILIES: [Family; 17] = [
+    Family::BlueViolet,
+    Family::Violet,
+    Family::RedViolet,
+    Family::Red,
+    Family::YellowRed,
+    Family::Yellow,
+    Family::YellowGreen,
+    Family::Green,
+    Family::BlueGreen,
+    Family::Blue,
+    Family::Earth,
+    Family::CoolGray,
+    Family::NeutralGray,
+    Family::TonerGray,
+    Family::WarmGray,
+    Family::Flourescent,
+    Family::Achromatic,
+];
+
+/// Number of colors printed by the `nearest` command.
+const NEAREST_COUNT: usize = 5;
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1).collect::<Vec<_>>();
+    let json = take_flag(&mut args, "--json");
+    let help = take_flag(&mut args, "--help") || take_flag(&mut args, "-h");
+
+    let mut out = io::stdout().lock();
+    let result = if help {
+        writeln!(out, "{USAGE}").map_err(Error::from)
+    } else {
+        run(&mut out, &args, json)
+    };
+    match result.and_then(|()| out.flush().map_err(Error::from)) {
+        Ok(()) => ExitCode::SUCCESS,
+        // The reader has gone away (e.g., `copic list | head`), so there is nothing more to do.
+        Err(Error::Io(e)) if e.kind() == io::ErrorKind::BrokenPipe => ExitCode::SUCCESS,
+        Err(Error::Io(e)) => {
+            eprintln!("error: {e}");
+            ExitCode::FAILURE
+        }
+        Err(Error::Usage(e)) => {
+            eprintln!("error: {e}\n\n{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Error of a command.
+enum Error {
+    /// The command line is invalid.
+    Usage(String),
+
+    /// Writing the output failed.
+    Io(io::Error),
+}
+
+impl From<String> for Error {
+    fn from(e: String) -> Self {
+        Self::Usage(e)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+fn run(out: &mut impl Write, args: &[String], json: bool) -> Result<(), Error> {
+    let Some((command, args)) = args.split_first() else {
+        return Err(Error::Usage("no command is specified".to_owned()));
+    };
+    match command.as_str() {
+        "show" => show(out, single_arg(args, "<CODE>")?, json),
+        "search" => search(out, &args.join(" "), json),
+        "nearest" => nearest(out, single_arg(args, "<RGB>")?, json),
+        "list" => list(out, args, json),
+        "help" => Ok(writeln!(out, "{USAGE}")?),
+        _ => Err(Error::Usage(format!("unknown command: {command:?}"))),
+    }
+}
+
+fn show(out: &mut impl Write, code: &str, json: bool) -> Result<(), Error> {
+    let color: Color = code.parse().map_err(|e| format!("{e}: {code:?}"))?;
+    let lab = color.lab();
+    if json {
+        print_json(out, &color_json(&color))?;
+    } else {
+        writeln!(out, "{}{} {}", swatch(color.rgb), color.code, color.name)?;
+        writeln!(out, "  family: {:?}", color.family)?;
+        writeln!(out, "  group:  {:?}", color.group)?;
+        writeln!(out, "  value:  {:?}", color.value)?;
+        writeln!(out, "  hex:    {}", color.rgb)?;
+        writeln!(out, "  lab:    {:.2}, {:.2}, {:.2}", lab.l, lab.a, lab.b)?;
+    }
+    Ok(())
+}
+
+fn search(out: &mut impl Write, text: &str, json: bool) -> Result<(), Error> {
+    if text.trim().is_empty() {
+        return Err(Error::Usage("missing <TEXT>".to_owned()));
+    }
+    let mut hits = [None; 20];
+    copic_colors::search(text, &mut hits);
+    let hits = hits.into_iter().flatten();
+    if json {
+        let items = hits
+            .map(|hit| {
+                let mut item = color_json(hit.color);
+                item["score"] = json!(hit.score);
+                item
+            })
+            .collect::<Vec<_>>();
+        print_json(out, &json!(items))?;
+    } else {
+        print_colors(out, hits.map(|hit| hit.color), false)?;
+    }
+    Ok(())
+}
+
+/// Prints the colors nearest to `rgb` in terms of CIEDE2000, which is also the printed distance.
+fn nearest(out: &mut impl Write, rgb: &str, json: bool) -> Result<(), Error> {
+    let rgb: Rgb = rgb.parse().map_err(|e| format!("{e}: {rgb:?}"))?;
+    let mut matches = copic_colors::ALL_COLORS
+        .iter()
+        .map(|color| (color, delta_e::ciede2000(rgb, color)))
+        .collect::<Vec<_>>();
+    matches.sort_by(|a, b| a.1.total_cmp(&b.1));
+    matches.truncate(NEAREST_COUNT);
+
+    if json {
+        let items = matches
+            .into_iter()
+            .map(|(color, delta_e)| {
+                let mut item = color_json(color);
+                item["delta_e"] = json!(delta_e);
+                item
+            })
+            .collect::<Vec<_>>();
+        print_json(out, &json!(items))?;
+    } else {
+        writeln!(out, "{}{rgb}", swatch(rgb))?;
+        for (color, delta_e) in matches {
+            writeln!(
+                out,
+                "{}{:<7} {:<24} ΔE00={:.2}",
+                swatch(color.rgb),
+                color.code,
+                color.name,
+                delta_e
+            )?;
+        }
+    }
+    Ok(())
+}
+
+fn list(out: &mut impl Write, mut args: &[String], json: bool) -> Result<(), Error> {
+    let mut query = Query::new();
+    while let Some((option, rest)) = args.split_first() {
+        let (value, rest) = rest
+            .split_first()
+            .ok_or_else(|| format!("missing value for {option}"))?;
+        query = match option.as_str() {
+            "--family" => query.family(parse_family(value)?),
+            "--group" => query.group(parse_range::<Group>(value)?),
+            "--value" => query.value(parse_range::<Value>(value)?),
+            _ => return Err(Error::Usage(format!("unknown option: {option:?}"))),
+        };
+        args = rest;
+    }
+    print_colors(out, query.iter(), json)?;
+    Ok(())
+}
+
+fn print_colors<'a>(
+    out: &mut impl Write,
+    colors: impl Iterator<Item = &'a Color>,
+    json: bool,
+) -> io::Result<()> {
+    if json {
+        print_json(out, &json!(colors.map(color_json).collect::<Vec<_>>()))
+    } else {
+        for color in colors {
+            writeln!(out, "{}{:<7} {}", swatch(color.rgb), color.code, color.name)?;
+        }
+        Ok(())
+    }
+}
+
+fn color_json(color: &Color) -> serde_json::Value {
+    let lab = color.lab();
+    let mut value = json!(color);
+    value["hex"] = json!(color.rgb.to_string());
+    value["lab"] = json!([lab.l, lab.a, lab.b]);
+    value
+}
+
+fn print_json(out: &mut impl Write, value: &serde_json::Value) -> io::Result<()> {
+    writeln!(out, "{value}")
+}
+
+/// Returns a truecolor terminal swatch followed by a space.
+///
+/// Returns an empty string if stdout is not a terminal, so that piped output has no escape codes.
+fn swatch(rgb: Rgb) -> String {
+    static IS_TERMINAL: LazyLock<bool> = LazyLock::new(|| io::stdout().is_terminal());
+    if *IS_TERMINAL {
+        format!("\x1b[48;2;{};{};{}m    \x1b[0m ", rgb.r, rgb.g, rgb.b)
+    } else {
+        String::new()
+    }
+}
+
+fn parse_family(s: &str) -> Result<Family, String> {
+    FAMILIES
+        .into_iter()
+        .find(|f| normalize(&format!("{f:?}")) == normalize(s))
+        .ok_or_else(|| format!("unknown family: {s:?}"))
+}
+
+/// Parses `X`, `X..Y` or `X..=Y` (both forms of ranges are inclusive), where either end may be omitted
+/// (e.g., `..B0`, `S3..`).
+fn parse_range<T: FromStr + Copy>(s: &str) -> Result<(Bound<T>, Bound<T>), String> {
+    let parse = |x: &str| {
+        if x.is_empty() {
+            return Ok(Bound::Unbounded);
+        }
+        x.parse::<T>()
+            .map(Bound::Included)
+            .map_err(|_| format!("invalid range: {s:?}"))
+    };
+    match s.split_once("..") {
+        Some((start, end)) => Ok((parse(start)?, parse(end.trim_start_matches('='))?)),
+        None => {
+            let x = s
+                .parse::<T>()
+                .map_err(|_| format!("invalid range: {s:?}"))?;
+            Ok((Bound::Included(x), Bound::Included(x)))
+        }
+    }
+}
+
+fn normalize(s: &str) -> String {
+    s.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+fn single_arg<'a>(args: &'a [String], name: &str) -> Result<&'a str, String> {
+    match args {
+        [arg] => Ok(arg),
+        [] => Err(format!("missing {name}")),
+        _ => Err(format!("too many arguments: {args:?}")),
+    }
+}
+
+fn take_flag(args: &mut Vec<String>, flag: &str) -> bool {
+    let len = args.len();
+    args.retain(|arg| arg != flag);
+    args.len() != len
+}
@@ -0,0 +1,58 @@
+use crate::{Color, Value, ALL_COLORS};
+
+const COLORS: &[Color] = &ALL_COLORS;
+
+impl Color {
+    /// Returns the markers sharing the family and group of this color, ordered from lightest to darkest.
+    ///
+    /// Markers in the same family and blending group with nearby values blend smoothly
+    /// (e.g., B21, B24 and B28). The returned slice includes this color itself.
+    /// For gray colors, whose group is [`Group::Undefined`](crate::Group::Undefined), the whole family is returned.
+    /// Colors without a defined value (e.g., fluorescent colors) have no companions other than themselves.
+    ///
+    /// Returns an empty slice if this color is not in [`ALL_COLORS`].
+    pub fn blending_companions(&self) -> &'static [Color] {
+        let Some(i) = self.catalogue_index() else {
+            return &[];
+        };
+        if self.value == Value::Undefined {
+            return &COLORS[i..=i];
+        }
+        let is_companion = |c: &Color| c.family == self.family && c.group == self.group;
+        let start = COLORS[..i]
+            .iter()
+            .rposition(|c| !is_companion(c))
+            .map_or(0, |j| j + 1);
+        let end = COLORS[i..]
+            .iter()
+            .position(|c| !is_companion(c))
+            .map_or(COLORS.len(), |j| i + j);
+        &COLORS[start..end]
+    }
+
+    /// Returns the next lighter marker in the same family and group.
+    pub fn lighter(&self) -> Option<&'static Color> {
+        let companions = self.blending_companions();
+        let i = companions.iter().position(|c| c.code == self.code)?;
+        i.checked_sub(1).map(|i| &companions[i])
+    }
+
+    /// Returns the next darker marker in the same family and group.
+    pub fn darker(&self) -> Option<&'static Color> {
+        let companions = self.blending_companions();
+        let i = companions.iter().position(|c| c.code == self.code)?;
+        companions.get(i + 1)
+    }
+
+    /// Returns a three-marker blend centered on this color (lighter, this, darker).
+    ///
+    /// Returns `None` if this color is the lightest or darkest marker in its group.
+    pub fn blending_trio(&self) -> Option<[&'static Color; 3]> {
+        let center = crate::lookup(self.code)?;
+        Some([self.lighter()?, center, self.darker()?])
+    }
+
+    fn catalogue_index(&self) -> Option<usize> {
+        crate::lookup::index_of(self.code).filter(|&i| COLORS[i] == *self)
+    }
+}
@@ -0,0 +1,633 @@
+use crate::math;
+use crate::{Color, Rgb};
+
+/// Reference white (D65, 2° observer) used by the conversions in this crate.
+pub const D65_WHITE: Xyz = Xyz::new(0.95047, 1.0, 1.08883);
+
+/// Linear (gamma-expanded) sRGB with components in the range `0.0..=1.0`.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct LinearRgb {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+impl LinearRgb {
+    /// Makes a new [`LinearRgb`] instance.
+    pub const fn new(r: f32, g: f32, b: f32) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Converts to CIE XYZ (D65).
+    pub fn to_xyz(self) -> Xyz {
+        let Self { r, g, b } = self;
+        Xyz::new(
+            0.4124564 * r + 0.3575761 * g + 0.1804375 * b,
+            0.2126729 * r + 0.7151522 * g + 0.0721750 * b,
+            0.0193339 * r + 0.119192 * g + 0.9503041 * b,
+        )
+    }
+
+    /// Converts to (gamma-compressed) sRGB.
+    ///
+    /// Out-of-gamut components are clamped.
+    pub fn to_rgb(self) -> Rgb {
+        Rgb::new(
+            linear_to_srgb(self.r),
+            linear_to_srgb(self.g),
+            linear_to_srgb(self.b),
+        )
+    }
+}
+
+/// CIE XYZ with the D65 reference white (`y` is `1.0` for the white point).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Xyz {
+    pub x: f32,
+    pub y: f32,
+    pub z: f32,
+}
+
+impl Xyz {
+    /// Makes a new [`Xyz`] instance.
+    pub const fn new(x: f32, y: f32, z: f32) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Converts to linear sRGB.
+    ///
+    /// The result may be out of the range `0.0..=1.0` if the color is out of the sRGB gamut.
+    pub fn to_linear_rgb(self) -> LinearRgb {
+        let Self { x, y, z } = self;
+        LinearRgb::new(
+            3.2404542 * x - 1.5371385 * y - 0.4985314 * z,
+            -0.969266 * x + 1.8760108 * y + 0.041556 * z,
+            0.0556434 * x - 0.2040259 * y + 1.0572252 * z,
+        )
+    }
+
+    /// Converts to CIELAB.
+    pub fn to_lab(self) -> Lab {
+        let fx = lab_f(self.x / D65_WHITE.x);
+        let fy = lab_f(self.y / D65_WHITE.y);
+        let fz = lab_f(self.z / D65_WHITE.z);
+        Lab::new(116.0 * fy - 16.0, 500.0 * (fx - fy), 200.0 * (fy - fz))
+    }
+}
+
+/// CIELAB (D65).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Lab {
+    /// Lightness (`0.0..=100.0`).
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+impl Lab {
+    /// Makes a new [`Lab`] instance.
+    pub const fn new(l: f32, a: f32, b: f32) -> Self {
+        Self { l, a, b }
+    }
+
+    /// Converts to CIE XYZ.
+    pub fn to_xyz(self) -> Xyz {
+        let fy = (self.l + 16.0) / 116.0;
+        let fx = fy + self.a / 500.0;
+        let fz = fy - self.b / 200.0;
+        Xyz::new(
+            lab_f_inv(fx) * D65_WHITE.x,
+            lab_f_inv(fy) * D65_WHITE.y,
+            lab_f_inv(fz) * D65_WHITE.z,
+        )
+    }
+
+    /// Converts to LCh(ab).
+    pub fn to_lch(self) -> Lch {
+        let c = math::hypotf(self.a, self.b);
+        let h = math::atan2f(self.b, self.a).to_degrees();
+        Lch::new(self.l, c, if h < 0.0 { h + 360.0 } else { h })
+    }
+
+    /// Converts to sRGB.
+    ///
+    /// Out-of-gamut colors are clamped.
+    pub fn to_rgb(self) -> Rgb {
+        self.to_xyz().to_linear_rgb().to_rgb()
+    }
+}
+
+/// LCh(ab), the cylindrical representation of CIELAB.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Lch {
+    /// Lightness (`0.0..=100.0`).
+    pub l: f32,
+
+    /// Chroma.
+    pub c: f32,
+
+    /// Hue angle in degrees (`0.0..360.0`).
+    pub h: f32,
+}
+
+impl Lch {
+    /// Makes a new [`Lch`] instance.
+    pub const fn new(l: f32, c: f32, h: f32) -> Self {
+        Self { l, c, h }
+    }
+
+    /// Converts to CIELAB.
+    pub fn to_lab(self) -> Lab {
+        let h = self.h.to_radians();
+        Lab::new(self.l, self.c * math::cosf(h), self.c * math::sinf(h))
+    }
+}
+
+impl Rgb {
+    /// Converts to linear sRGB.
+    pub fn to_linear_rgb(self) -> LinearRgb {
+        LinearRgb::new(
+            srgb_to_linear(self.r),
+            srgb_to_linear(self.g),
+            srgb_to_linear(self.b),
+        )
+    }
+
+    /// Converts to CIE XYZ (D65).
+    pub fn to_xyz(self) -> Xyz {
+        self.to_linear_rgb().to_xyz()
+    }
+
+    /// Converts to CIELAB (D65).
+    pub fn to_lab(self) -> Lab {
+        self.to_xyz().to_lab()
+    }
+
+    /// Converts to LCh(ab) (D65).
+    pub fn to_lch(self) -> Lch {
+        self.to_lab().to_lch()
+    }
+}
+
+impl From<Rgb> for Lab {
+    fn from(rgb: Rgb) -> Self {
+        rgb.to_lab()
+    }
+}
+
+impl From<Color> for Lab {
+    fn from(color: Color) -> Self {
+        color.lab()
+    }
+}
+
+impl From<&Color> for Lab {
+    fn from(color: &Color) -> Self {
+        color.lab()
+    }
+}
+
+impl Color {
+    /// Returns the CIELAB coordinates of this color.
+    ///
+    /// For colors in [`ALL_COLORS`](crate::ALL_COLORS), this returns a value precomputed at build time.
+    pub fn lab(&self) -> Lab {
+        match crate::lookup::index_of(self.code) {
+            Some(i) if crate::ALL_COLORS[i].rgb == self.rgb => ALL_LABS[i],
+            _ => self.rgb.to_lab(),
+        }
+    }
+
+    /// Returns the LCh(ab) coordinates of this color.
+    pub fn lch(&self) -> Lch {
+        self.lab().to_lch()
+    }
+}
+
+fn srgb_to_linear(c: u8) -> f32 {
+    let c = f32::from(c) / 255.0;
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        math::powf((c + 0.055) / 1.055, 2.4)
+    }
+}
+
+fn linear_to_srgb(c: f32) -> u8 {
+    let c = if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * math::powf(c, 1.0 / 2.4) - 0.055
+    };
+    math::roundf(c.clamp(0.0, 1.0) * 255.0) as u8
+}
+
+const LAB_EPSILON: f32 = 216.0 / 24389.0;
+const LAB_KAPPA: f32 = 24389.0 / 27.0;
+
+fn lab_f(t: f32) -> f32 {
+    if t > LAB_EPSILON {
+        math::cbrtf(t)
+    } else {
+        (LAB_KAPPA * t + 16.0) / 116.0
+    }
+}
+
+fn lab_f_inv(t: f32) -> f32 {
+    let t3 = t * t * t;
+    if t3 > LAB_EPSILON {
+        t3
+    } else {
+        (116.0 * t - 16.0) / LAB_KAPPA
+    }
+}
+
+/// CIELAB coordinates of [`ALL_COLORS`](crate::ALL_COLORS) (in the same order).
+static ALL_LABS: [Lab; crate::ALL_COLORS.len()] = [
+    Lab::new(93.7798, 2.2826, -4.1032),    // BV0000
+    Lab::new(92.4335, 4.1294, -4.0009),    // BV000
+    Lab::new(91.0317, 4.3590, -5.5873),    // BV00
+    Lab::new(80.2283, 6.5635, -12.9055),   // BV01
+    Lab::new(79.4736, 3.3352, -14.1139),   // BV02
+    Lab::new(67.3647, 3.3251, -23.4820),   // BV04
+    Lab::new(62.9189, 21.7241, -18.3043),  // BV08
+    Lab::new(88.4905, 4.2848, -7.3204),    // BV11
+    Lab::new(66.0167, 7.2884, -22.7312),   // BV13
+    Lab::new(59.9386, 4.4183, -25.4891),   // BV17
+    Lab::new(90.1205, 0.4944, -8.0898),    // BV20
+    Lab::new(82.2894, 0.8849, -10.9282),   // BV23
+    Lab::new(60.3846, 6.4661, -15.0567),   // BV25
+    Lab::new(27.1547, -1.7878, -14.4427),  // BV29
+    Lab::new(92.1617, 1.5348, -6.0349),    // BV31
+    Lab::new(60.5540, 3.5637, -24.5369),   // BV34
+    Lab::new(95.4727, 1.9115, -3.1282),    // V0000
+    Lab::new(95.9943, 2.5433, -1.8105),    // V000
+    Lab::new(85.2334, 14.5399, -4.6051),   // V01
+    Lab::new(80.3526, 22.4347, -4.8382),   // V04
+    Lab::new(79.0032, 24.2602, -5.2189),   // V05
+    Lab::new(73.5776, 25.0733, -9.0850),   // V06
+    Lab::new(47.0507, 36.0003, -24.9494),  // V09
+    Lab::new(90.6207, 8.5443, -3.4924),    // V12
+    Lab::new(78.4082, 19.5324, -7.8521),   // V15
+    Lab::new(68.8938, 13.8319, -17.0457),  // V17
+    Lab::new(83.0129, 4.9064, -3.8416),    // V20
+    Lab::new(48.9992, 11.5978, -16.2872),  // V22
+    Lab::new(72.4248, 7.4523, -11.1597),   // V25
+    Lab::new(52.3420, 7.9280, -20.5794),   // V28
+    Lab::new(86.2170, 12.1144, 0.0449),    // V91
+    Lab::new(85.5021, 14.1843, -4.7407),   // V93
+    Lab::new(63.8894, 27.9154, -8.1493),   // V95
+    Lab::new(25.4606, 17.5464, -10.8571),  // V99
+    Lab::new(95.1525, 3.5679, -2.5357),    // RV0000
+    Lab::new(93.6255, 6.1028, -2.1690),    // RV000
+    Lab::new(87.7408, 14.8029, -6.6763),   // RV00
+    Lab::new(91.1540, 12.1203, -1.5647),   // RV02
+    Lab::new(79.1707, 29.5531, 0.0592),    // RV04
+    Lab::new(68.2319, 44.3616, -3.4359),   // RV06
+    Lab::new(65.3996, 47.1300, -6.5799),   // RV09
+    Lab::new(96.2347, 5.0404, -0.3605),    // RV10
+    Lab::new(91.2427, 10.6114, 1.7082),    // RV11
+    Lab::new(88.2375, 16.0482, -0.0430),   // RV13
+    Lab::new(75.2848, 36.3066, -0.7210),   // RV14
+    Lab::new(68.3603, 39.7013, -7.2965),   // RV17
+    Lab::new(62.1554, 47.6471, -8.7014),   // RV19
+    Lab::new(95.3020, 5.2223, 2.4223),     // RV21
+    Lab::new(84.6921, 20.1673, 2.7441),    // RV23
+    Lab::new(75.6377, 36.4931, -2.9476),   // RV25
+    Lab::new(57.2590, 67.1417, 6.5475),    // RV29
+    Lab::new(90.3112, 10.6122, 6.6602),    // RV32
+    Lab::new(81.8055, 22.1252, 10.8998),   // RV34
+    Lab::new(84.5459, 17.3099, 11.0587),   // RV42
+    Lab::new(71.1269, 27.6693, -3.8753),   // RV52
+    Lab::new(57.5659, 52.5252, -5.7861),   // RV55
+    Lab::new(66.9577, 36.8074, -9.5115),   // RV63
+    Lab::new(36.6235, 41.5639, -8.5963),   // RV66
+    Lab::new(48.4017, 26.3405, -1.3383),   // RV69
+    Lab::new(89.8937, 6.8486, -3.0332),    // RV91
+    Lab::new(82.8403, 17.6370, -1.7075),   // RV93
+    Lab::new(66.5883, 22.2191, -4.1379),   // RV95
+    Lab::new(39.6677, 11.8487, -3.8681),   // RV99
+    Lab::new(97.2350, 1.3110, 2.6278),     // R0000
+    Lab::new(97.4637, 2.3218, 2.9882),     // R000
+    Lab::new(97.1454, 2.3280, 4.0731),     // R00
+    Lab::new(91.3508, 9.0080, 6.5861),     // R01
+    Lab::new(90.2357, 9.9079, 10.2329),    // R02
+    Lab::new(73.3951, 31.3456, 26.7604),   // R05
+    Lab::new(62.3790, 50.2944, 38.1533),   // R08
+    Lab::new(93.5353, 6.0613, 8.1851),     // R11
+    Lab::new(90.1239, 9.1738, 12.6921),    // R12
+    Lab::new(76.4260, 28.5133, 17.8805),   // R14
+    Lab::new(69.9223, 36.5319, 32.4731),   // R17
+    Lab::new(91.4122, 9.2052, 7.2084),     // R20
+    Lab::new(80.2548, 24.0856, 15.1696),   // R21
+    Lab::new(73.7704, 38.0907, 16.4777),   // R22
+    Lab::new(66.6786, 45.3022, 19.2564),   // R24
+    Lab::new(58.0272, 61.8021, 24.6660),   // R27
+    Lab::new(50.4663, 77.7276, 32.0458),   // R29
+    Lab::new(94.1303, 5.9577, 4.8746),     // R30
+    Lab::new(85.9412, 15.7624, 10.4038),   // R32
+    Lab::new(65.8821, 48.5402, 13.6347),   // R35
+    Lab::new(64.0984, 45.4429, 18.3632),   // R37
+    Lab::new(54.1311, 55.6017, 0.8718),    // R39
+    Lab::new(69.8609, 37.8119, 12.4060),   // R43
+    Lab::new(55.7553, 59.9079, 15.9823),   // R46
+    Lab::new(54.5412, 39.0396, 6.4211),    // R56
+    Lab::new(53.5064, 43.6787, 3.1996),    // R59
+    Lab::new(87.8958, 13.1139, 0.4511),    // R81
+    Lab::new(77.2371, 30.4282, 0.4491),    // R83
+    Lab::new(62.3581, 44.4040, -0.9577),   // R85
+    Lab::new(36.1828, 40.9481, 6.1184),    // R89
+    Lab::new(97.0674, 0.1387, 5.9934),     // YR0000
+    Lab::new(95.8339, 2.0944, 9.4091),     // YR000
+    Lab::new(90.7671, 7.0528, 15.6914),    // YR00
+    Lab::new(88.1595, 9.1451, 20.3874),    // YR01
+    Lab::new(92.2445, 5.9971, 13.6243),    // YR02
+    Lab::new(85.5008, 5.9665, 54.3511),    // YR04
+    Lab::new(64.3794, 44.1698, 57.8349),   // YR07
+    Lab::new(57.9203, 57.2404, 67.9358),   // YR09
+    Lab::new(90.8055, -1.5256, 43.3612),   // YR12
+    Lab::new(86.1933, 1.9226, 69.4261),    // YR14
+    Lab::new(86.5691, 8.8012, 34.3577),    // YR15
+    Lab::new(82.2745, 8.0177, 81.2745),    // YR16
+    Lab::new(63.2906, 46.2876, 55.8615),   // YR18
+    Lab::new(93.3448, 2.5775, 18.2859),    // YR20
+    Lab::new(91.4982, 0.1178, 22.8646),    // YR21
+    Lab::new(87.8769, -1.3919, 35.8602),   // YR23
+    Lab::new(87.8933, -4.4815, 57.8381),   // YR24
+    Lab::new(56.8146, 41.9356, 39.7327),   // YR27
+    Lab::new(93.6936, -2.3420, 18.6791),   // YR30
+    Lab::new(92.1630, 1.0438, 28.4986),    // YR31
+    Lab::new(91.6064, 6.2269, 14.2705),    // YR61
+    Lab::new(80.4911, 15.3684, 52.2852),   // YR65
+    Lab::new(63.9924, 43.7671, 71.5398),   // YR68
+    Lab::new(86.5691, 8.8012, 34.3577),    // YR82
+    Lab::new(99.5572, -0.8762, 3.4564),    // Y0000
+    Lab::new(99.7807, -1.5485, 4.2930),    // Y000
+    Lab::new(99.1436, -3.8277, 12.0815),   // Y00
+    Lab::new(95.1543, -10.8645, 41.4430),  // Y02
+    Lab::new(90.7614, -13.7227, 71.0197),  // Y04
+    Lab::new(95.5799, -13.7512, 63.6845),  // Y06
+    Lab::new(94.5546, -16.7689, 92.5939),  // Y08
+    Lab::new(98.2671, -5.5618, 20.0382),   // Y11
+    Lab::new(96.7659, -8.9764, 32.1370),   // Y13
+    Lab::new(93.2882, -9.3310, 61.0710),   // Y15
+    Lab::new(92.0269, -9.1956, 71.8613),   // Y17
+    Lab::new(94.0789, -13.0254, 73.1947),  // Y18
+    Lab::new(92.8863, -12.3729, 82.1678),  // Y19
+    Lab::new(95.5733, -1.9812, 21.3952),   // Y21
+    Lab::new(93.2102, -0.1767, 23.7450),   // Y23
+    Lab::new(90.2211, -8.7003, 58.6998),   // Y26
+    Lab::new(75.0275, 2.4115, 40.2791),    // Y28
+    Lab::new(92.2956, 2.8044, 16.2469),    // Y32
+    Lab::new(89.9333, -1.1101, 50.1169),   // Y35
+    Lab::new(98.0662, -14.2287, 48.6633),  // Y38
+    Lab::new(97.2792, -4.2280, 9.8519),    // YG0000
+    Lab::new(91.8409, -9.3514, 33.9372),   // YG00
+    Lab::new(94.2491, -8.2236, 19.8677),   // YG01
+    Lab::new(92.5116, -10.7881, 28.6919),  // YG03
+    Lab::new(90.2052, -14.4523, 38.1574),  // YG05
+    Lab::new(87.5114, -17.2508, 34.9261),  // YG06
+    Lab::new(79.5018, -28.5977, 69.0701),  // YG07
+    Lab::new(74.1765, -33.3741, 45.0343),  // YG09
+    Lab::new(94.6271, -7.4236, 13.6865),   // YG11
+    Lab::new(90.1924, -13.9512, 31.5373),  // YG13
+    Lab::new(70.7966, -41.8579, 50.9313),  // YG17
+    Lab::new(96.5551, -7.5696, 24.2159),   // YG21
+    Lab::new(92.7300, -12.5934, 42.1141),  // YG23
+    Lab::new(88.0819, -17.1287, 48.1641),  // YG25
+    Lab::new(92.6481, -8.0288, 8.6878),    // YG41
+    Lab::new(86.4538, -15.1520, 13.6870),  // YG45
+    Lab::new(78.6491, -16.3118, 26.8942),  // YG61
+    Lab::new(81.2126, -16.8731, 16.1631),  // YG63
+    Lab::new(75.1930, -25.0037, 20.7904),  // YG67
+    Lab::new(88.3192, -4.5883, 19.7679),   // YG91
+    Lab::new(86.4777, -7.2942, 26.5138),   // YG93
+    Lab::new(82.9848, -10.7362, 53.4099),  // YG95
+    Lab::new(64.7149, -10.7234, 67.7009),  // YG97
+    Lab::new(47.6202, -18.8710, 52.7143),  // YG99
+    Lab::new(97.8804, -2.0241, 1.4516),    // G0000
+    Lab::new(98.2268, -2.0226, 1.4505),    // G000
+    Lab::new(95.7683, -3.4329, -1.7361),   // G00
+    Lab::new(91.7118, -9.0503, 7.2881),    // G02
+    Lab::new(83.4445, -30.8669, 41.7287),  // G03
+    Lab::new(71.8514, -35.3978, 29.2464),  // G05
+    Lab::new(74.5305, -32.5505, 34.4771),  // G07
+    Lab::new(73.6512, -34.3738, 44.3470),  // G09
+    Lab::new(91.6095, -10.3789, 14.4435),  // G12
+    Lab::new(80.0537, -24.4181, 26.5481),  // G14
+    Lab::new(72.4882, -31.6351, 11.3855),  // G16
+    Lab::new(63.4928, -46.8797, 15.2897),  // G17
+    Lab::new(67.0551, -41.7003, 13.2642),  // G19
+    Lab::new(96.3197, -5.5873, 10.4881),   // G20
+    Lab::new(89.9820, -10.7789, 7.8574),   // G21
+    Lab::new(88.2708, -13.4781, 17.9278),  // G24
+    Lab::new(55.1833, -43.1001, 17.1140),  // G28
+    Lab::new(50.7485, -30.3668, 9.9324),   // G29
+    Lab::new(95.1947, -5.6226, 7.2701),    // G40
+    Lab::new(68.8407, -25.6168, 40.5195),  // G43
+    Lab::new(52.4708, -27.4965, 18.1882),  // G46
+    Lab::new(88.6564, -7.5107, 13.8802),   // G82
+    Lab::new(79.9438, -13.2869, 9.0299),   // G85
+    Lab::new(71.3777, -9.6488, 17.8794),   // G94
+    Lab::new(56.0035, -19.3523, 39.2381),  // G99
+    Lab::new(97.5584, -1.8498, 0.9737),    // BG0000
+    Lab::new(98.2762, -1.6708, 0.4941),    // BG000
+    Lab::new(91.9037, -4.3427, -8.6280),   // BG01
+    Lab::new(91.4721, -7.1487, -3.5429),   // BG02
+    Lab::new(82.0372, -14.2054, -14.2333), // BG05
+    Lab::new(69.2082, -26.3398, -23.2201), // BG07
+    Lab::new(66.4484, -27.3096, -24.7346), // BG09
+    Lab::new(94.9501, -4.1316, -0.8891),   // BG10
+    Lab::new(92.7793, -5.7907, -4.1789),   // BG11
+    Lab::new(91.0945, -7.3479, -3.0617),   // BG13
+    Lab::new(85.1949, -14.4229, -0.9019),  // BG15
+    Lab::new(70.9103, -32.7331, -3.5815),  // BG18
+    Lab::new(89.6321, -9.8706, 0.5021),    // BG23
+    Lab::new(89.1791, -10.6351, 2.4533),   // BG32
+    Lab::new(85.7787, -12.9736, -2.6672),  // BG34
+    Lab::new(87.6182, -10.5638, -4.1155),  // BG45
+    Lab::new(66.2345, -32.8125, -15.6521), // BG49
+    Lab::new(69.0419, -24.9093, -11.8242), // BG53
+    Lab::new(60.6763, -30.6008, -15.6860), // BG57
+    Lab::new(93.1687, -4.9501, 4.2816),    // BG70
+    Lab::new(73.4867, -16.7657, -7.1751),  // BG72
+    Lab::new(85.1949, -14.4229, -0.9019),  // BG75
+    Lab::new(45.8540, -17.9821, 0.6286),   // BG78
+    Lab::new(81.9480, -1.6846, 10.5858),   // BG90
+    Lab::new(82.2844, -2.8275, 4.6093),    // BG93
+    Lab::new(69.7455, -11.1355, 6.6662),   // BG96
+    Lab::new(66.4400, -14.8818, 6.1916),   // BG99
+    Lab::new(98.3299, -1.1057, -2.0075),   // B0000
+    Lab::new(96.2324, -2.9614, -1.0316),   // B000
+    Lab::new(96.0974, -3.3851, -2.8041),   // B00
+    Lab::new(94.0502, -4.8780, -2.7753),   // B01
+    Lab::new(89.2773, -8.6895, -8.4629),   // B02
+    Lab::new(80.1965, -15.9053, -17.6128), // B04
+    Lab::new(75.0108, -19.6054, -24.5734), // B05
+    Lab::new(65.7426, -17.7781, -37.3971), // B06
+    Lab::new(91.4293, -5.4169, -5.1561),   // B12
+    Lab::new(80.3248, -14.8891, -19.5554), // B14
+    Lab::new(70.0984, -23.3179, -33.3629), // B16
+    Lab::new(57.0136, -9.6931, -37.0959),  // B18
+    Lab::new(94.5831, -1.9446, -5.5820),   // B21
+    Lab::new(80.3206, -4.0090, -18.3305),  // B23
+    Lab::new(82.7660, -9.6390, -18.9484),  // B24
+    Lab::new(73.2826, -8.9521, -26.0307),  // B26
+    Lab::new(45.7742, -1.9096, -40.5005),  // B28
+    Lab::new(47.9266, -2.6319, -42.9011),  // B29
+    Lab::new(95.2906, -2.2336, -2.9540),   // B32
+    Lab::new(79.4291, -8.0709, -20.8417),  // B34
+    Lab::new(45.2248, -8.4459, -33.7699),  // B37
+    Lab::new(43.5772, 0.8272, -38.6844),   // B39
+    Lab::new(95.3954, -1.4884, -4.8706),   // B41
+    Lab::new(77.6526, -9.4194, -23.0653),  // B45
+    Lab::new(84.4144, -4.7955, -7.7912),   // B52
+    Lab::new(92.0152, 0.8842, -6.2678),    // B60
+    Lab::new(79.9369, 2.2682, -15.0443),   // B63
+    Lab::new(49.8160, 11.8196, -36.2272),  // B66
+    Lab::new(42.3010, 1.0830, -40.7332),   // B69
+    Lab::new(30.4387, 19.9844, -45.2606),  // B79
+    Lab::new(91.8637, -1.9220, -2.8630),   // B91
+    Lab::new(80.0082, -6.2940, -13.4452),  // B93
+    Lab::new(69.8577, -7.1906, -17.5415),  // B95
+    Lab::new(50.8464, -10.5841, -21.3965), // B97
+    Lab::new(31.1422, -2.3009, -31.2210),  // B99
+    Lab::new(98.4731, 0.4645, 3.9377),     // E0000
+    Lab::new(97.9319, 1.1407, 4.1822),     // E000
+    Lab::new(97.3676, 1.6550, 4.9103),     // E00
+    Lab::new(96.2651, 2.8659, 5.8969),     // E01
+    Lab::new(95.9490, 2.8895, 6.9912),     // E02
+    Lab::new(84.2419, 12.5847, 2.4529),    // E04
+    Lab::new(67.0278, 23.8233, 24.7063),   // E07
+    Lab::new(59.3550, 35.6813, 30.0986),   // E08
+    Lab::new(61.0355, 40.0482, 37.5410),   // E09
+    Lab::new(95.2738, 2.6419, 10.1576),    // E11
+    Lab::new(86.0233, 6.5860, 15.1158),    // E13
+    Lab::new(83.9489, 13.1084, 31.3718),   // E15
+    Lab::new(53.9181, 35.8469, 26.6065),   // E17
+    Lab::new(43.8446, 25.5593, 18.6694),   // E18
+    Lab::new(51.3820, 46.0165, 49.6451),   // E19
+    Lab::new(93.5342, 3.8792, 13.8938),    // E21
+    Lab::new(51.1106, 22.3219, 28.0051),   // E23
+    Lab::new(76.8319, 9.9176, 23.4053),    // E25
+    Lab::new(58.9062, 11.0030, 19.0347),   // E27
+    Lab::new(36.9445, 31.6023, 44.2057),   // E29
+    Lab::new(94.3180, -2.4692, 16.4612),   // E30
+    Lab::new(93.6634, -0.0785, 11.3863),   // E31
+    Lab::new(89.1373, 4.2657, 18.5219),    // E33
+    Lab::new(87.6162, 5.1140, 21.6161),    // E34
+    Lab::new(85.7491, 4.9550, 19.4580),    // E35
+    Lab::new(71.2992, 11.8749, 39.8371),   // E37
+    Lab::new(60.4372, 27.2395, 52.2973),   // E39
+    Lab::new(95.0565, 0.4839, 6.1421),     // E40
+    Lab::new(96.7338, 1.6881, 7.0887),     // E41
+    Lab::new(92.2626, -0.0738, 11.4240),   // E42
+    Lab::new(91.4293, -0.9973, 14.3789),   // E43
+    Lab::new(81.3732, 0.6509, 10.8673),    // E44
+    Lab::new(56.0065, 5.7276, 15.3342),    // E47
+    Lab::new(41.8398, 8.4052, 18.3402),    // E49
+    Lab::new(95.3483, 2.5137, 0.3509),     // E50
+    Lab::new(95.7664, 1.6257, 10.8615),    // E51
+    Lab::new(93.4264, -1.7540, 16.7442),   // E53
+    Lab::new(91.6818, -0.2330, 18.4258),   // E55
+    Lab::new(66.2310, 8.6765, 30.8228),    // E57
+    Lab::new(61.7748, 6.9924, 17.9058),    // E59
+    Lab::new(92.7820, 1.5003, 4.3543),     // E70
+    Lab::new(90.1258, 2.2039, 4.6302),     // E71
+    Lab::new(65.3582, 8.3175, 10.6944),    // E74
+    Lab::new(50.4470, 10.1848, 19.7576),   // E77
+    Lab::new(26.1923, 9.9683, 11.4904),    // E79
+    Lab::new(82.4576, -2.7033, 28.3296),   // E81
+    Lab::new(63.3644, 4.4257, 32.7680),    // E84
+    Lab::new(33.6004, 2.3874, 14.6463),    // E87
+    Lab::new(49.1824, 0.8377, 6.4903),     // E89
+    Lab::new(89.6879, 8.4143, 16.7837),    // E93
+    Lab::new(83.8089, 11.9776, 38.9461),   // E95
+    Lab::new(75.0243, 20.1221, 47.0330),   // E97
+    Lab::new(52.4363, 31.4049, 58.9535),   // E99
+    Lab::new(96.0199, -0.6352, -1.3084),   // C-00
+    Lab::new(95.1778, -1.2942, -1.5408),   // C-0
+    Lab::new(92.3332, -1.4487, -2.1489),   // C-1
+    Lab::new(88.7634, -1.6044, -2.7688),   // C-2
+    Lab::new(85.0270, -0.7617, -3.0526),   // C-3
+    Lab::new(77.6248, -1.5915, -3.9630),   // C-4
+    Lab::new(70.4403, -2.7005, -3.2542),   // C-5
+    Lab::new(63.5232, -1.6391, -4.1210),   // C-6
+    Lab::new(52.6628, -2.1118, -3.1875),   // C-7
+    Lab::new(44.0312, -1.4248, -3.0346),   // C-8
+    Lab::new(34.6063, 0.6030, 0.8752),     // C-9
+    Lab::new(11.3702, 0.4744, 0.1684),     // C-10
+    Lab::new(95.4936, 0.0, 0.0),           // N-0
+    Lab::new(93.0481, 0.0, 0.0),           // N-1
+    Lab::new(90.2365, 0.0, 0.0),           // N-2
+    Lab::new(87.7609, 0.0, 0.0),           // N-3
+    Lab::new(80.9650, 0.0, 0.0),           // N-4
+    Lab::new(74.1262, 0.3508, 0.1238),     // N-5
+    Lab::new(67.3698, 0.0, 0.0),           // N-6
+    Lab::new(55.2599, 0.5693, -0.4064),    // N-7
+    Lab::new(46.5215, 0.3847, 0.1359),     // N-8
+    Lab::new(34.6063, 0.6030, 0.8752),     // N-9
+    Lab::new(11.3702, 0.4744, 0.1684),     // N-10
+    Lab::new(95.4936, 0.0, 0.0),           // T-0
+    Lab::new(94.7461, -0.3526, 0.9637),    // T-1
+    Lab::new(92.2715, -0.5307, 1.4540),    // T-2
+    Lab::new(87.7104, -0.5480, 2.5703),    // T-3
+    Lab::new(80.6558, -0.0297, 2.2396),    // T-4
+    Lab::new(73.7868, 0.1473, 2.9162),     // T-5
+    Lab::new(66.6739, -0.0277, 2.3296),    // T-6
+    Lab::new(54.8427, 0.1660, 1.8823),     // T-7
+    Lab::new(46.1200, 0.1743, 1.9460),     // T-8
+    Lab::new(34.2155, 0.6058, 2.2017),     // T-9
+    Lab::new(11.3702, 0.4744, 0.1684),     // T-10
+    Lab::new(97.0840, -1.0441, 2.8778),    // W-00
+    Lab::new(96.8110, -0.7097, 2.9969),    // W-0
+    Lab::new(93.6738, -0.7141, 3.0183),    // W-1
+    Lab::new(93.7230, -0.3676, 2.0506),    // W-2
+    Lab::new(87.6610, -0.8972, 3.5522),    // W-3
+    Lab::new(80.8638, -0.9104, 3.6133),    // W-4
+    Lab::new(74.0495, -0.3888, 3.2968),    // W-5
+    Lab::new(66.8906, -0.9406, 3.7580),    // W-6
+    Lab::new(55.1786, -0.2142, 2.9589),    // W-7
+    Lab::new(46.3803, -0.6088, 2.9213),    // W-8
+    Lab::new(34.1865, 0.4040, 2.7914),     // W-9
+    Lab::new(11.3353, 0.2311, 0.8531),     // W-10
+    Lab::new(100.0000, 0.0, 0.0),          // 0
+    Lab::new(0.2742, 0.0, 0.0),            // 100
+    Lab::new(0.2742, 0.0, 0.0),            // 110
+    Lab::new(51.5055, 22.1512, -32.2381),  // FV
+    Lab::new(79.3316, 30.4435, -2.9584),   // FRV
+    Lab::new(88.2299, 7.0386, 30.3882),    // FYR
+    Lab::new(96.6366, -10.5587, 43.4474),  // FY
+    Lab::new(88.7356, -12.0312, 27.4903),  // FYG
+    Lab::new(78.0582, -31.0827, 75.3782),  // FG
+    Lab::new(78.4722, -17.0718, -20.2791), // FBG
+    Lab::new(57.4086, -11.3558, -38.7570), // FB
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ALL_COLORS;
+
+    #[test]
+    fn precomputed_labs_match_conversion() {
+        for (color, lab) in ALL_COLORS.iter().zip(&ALL_LABS) {
+            let expected = color.rgb.to_lab();
+            for (x, y) in [
+                (lab.l, expected.l),
+                (lab.a, expected.a),
+                (lab.b, expected.b),
+            ] {
+                assert!(
+                    (x - y).abs() < 1e-4,
+                    "{}: {lab:?} != {expected:?}",
+                    color.code
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,359 @@
+use crate::{is_code_separator, Color, Family, Group, Value};
+
+/// Structured representation of a Copic color code.
+///
+/// Codes are parsed according to the Copic naming system:
+/// - Chromatic colors: family prefix, group digit and value digits (e.g., "YG17", "BV0000")
+/// - Grays: family prefix and tone number (e.g., "N-5", "C-00")
+/// - Fluorescents: "F" followed by a hue prefix (e.g., "FYR")
+/// - Specials: "0" (colorless blender), "100" (black) and "110" (special black)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CopicCode {
+    /// Chromatic (or earth) color code such as "YG17".
+    Hue {
+        family: Family,
+        group: Group,
+        value: Value,
+    },
+
+    /// Gray color code such as "N-5".
+    Gray { family: Family, tone: GrayTone },
+
+    /// Fluorescent color code such as "FYR".
+    ///
+    /// `hue` is the chromatic family the fluorescent color belongs to (e.g., [`Family::YellowRed`] for "FYR").
+    Fluorescent { hue: Family },
+
+    /// "0" (Colorless Blender).
+    Blender,
+
+    /// "100" (Black).
+    Black,
+
+    /// "110" (Special Black).
+    SpecialBlack,
+}
+
+impl CopicCode {
+    /// Makes a chromatic color code.
+    ///
+    /// Returns `None` if `family` does not use the "prefix + group + value" notation,
+    /// if `group` or `value` is undefined, or if `value` is [`Value::B00`] or [`Value::B000`]
+    /// and `group` is not [`Group::S0`].
+    pub const fn hue(family: Family, group: Group, value: Value) -> Option<Self> {
+        if hue_prefix(family).is_none() || !is_valid_group_value(group, value) {
+            return None;
+        }
+        Some(Self::Hue {
+            family,
+            group,
+            value,
+        })
+    }
+
+    /// Makes a gray color code.
+    ///
+    /// Returns `None` if `family` is not a gray family.
+    pub const fn gray(family: Family, tone: GrayTone) -> Option<Self> {
+        if gray_prefix(family).is_none() {
+            return None;
+        }
+        Some(Self::Gray { family, tone })
+    }
+
+    /// Makes a fluorescent color code.
+    ///
+    /// Returns `None` if there is no fluorescent notation for `hue`.
+    pub const fn fluorescent(hue: Family) -> Option<Self> {
+        if fluorescent_hue_prefix(hue).is_none() {
+            return None;
+        }
+        Some(Self::Fluorescent { hue })
+    }
+
+    /// Returns the family of colors having this code.
+    pub const fn family(self) -> Family {
+        match self {
+            Self::Hue { family, .. } | Self::Gray { family, .. } => family,
+            Self::Fluorescent { .. } => Family::Flourescent,
+            Self::Blender | Self::Black | Self::SpecialBlack => Family::Achromatic,
+        }
+    }
+
+    /// Finds the color having this code in [`ALL_COLORS`](crate::ALL_COLORS).
+    pub fn color(self) -> Option<Color> {
+        crate::ALL_COLORS
+            .iter()
+            .find(|c| c.code.parse() == Ok(self))
+            .copied()
+    }
+}
+
+impl core::str::FromStr for CopicCode {
+    type Err = ParseCodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut buf = [0; 8];
+        let mut len = 0;
+        for b in s.bytes().filter(|b| !is_code_separator(*b)) {
+            if len == buf.len() {
+                return Err(ParseCodeError::TooLong);
+            }
+            buf[len] = b.to_ascii_uppercase();
+            len += 1;
+        }
+        let s = &buf[..len];
+        if s.is_empty() {
+            return Err(ParseCodeError::Empty);
+        }
+
+        match s {
+            b"0" => return Ok(Self::Blender),
+            b"100" => return Ok(Self::Black),
+            b"110" => return Ok(Self::SpecialBlack),
+            _ => {}
+        }
+
+        if let Some(rest) = s.strip_prefix(b"F") {
+            let (hue, rest) = strip_family_prefix(rest, fluorescent_hue_prefix)
+                .ok_or(ParseCodeError::UnknownPrefix)?;
+            if !rest.is_empty() {
+                return Err(ParseCodeError::InvalidSuffix);
+            }
+            return Ok(Self::Fluorescent { hue });
+        }
+
+        if let Some((family, rest)) = strip_family_prefix(s, gray_prefix) {
+            let tone = GrayTone::from_digits(rest).ok_or(ParseCodeError::InvalidSuffix)?;
+            return Ok(Self::Gray { family, tone });
+        }
+
+        let (family, rest) =
+            strip_family_prefix(s, hue_prefix).ok_or(ParseCodeError::UnknownPrefix)?;
+        let (&group, value) = rest.split_first().ok_or(ParseCodeError::InvalidSuffix)?;
+        let group = group
+            .checked_sub(b'0')
+            .and_then(Group::from_digit)
+            .ok_or(ParseCodeError::InvalidSuffix)?;
+        let value = Value::from_suffix_bytes(value).ok_or(ParseCodeError::InvalidSuffix)?;
+        if !is_valid_group_value(group, value) {
+            return Err(ParseCodeError::InvalidSuffix);
+        }
+        Ok(Self::Hue {
+            family,
+            group,
+            value,
+        })
+    }
+}
+
+impl core::fmt::Display for CopicCode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::Hue {
+                family,
+                group,
+                value,
+            } => {
+                let prefix = hue_prefix(family).unwrap_or("?");
+                let group = group.as_digit().map_or('?', |d| char::from(b'0' + d));
+                write!(f, "{prefix}{group}{}", value.suffix().unwrap_or("?"))
+            }
+            Self::Gray { family, tone } => {
+                let prefix = gray_prefix(family).unwrap_or("?");
+                write!(f, "{prefix}-{}", tone.as_str())
+            }
+            Self::Fluorescent { hue } => {
+                write!(f, "F{}", fluorescent_hue_prefix(hue).unwrap_or("?"))
+            }
+            Self::Blender => write!(f, "0"),
+            Self::Black => write!(f, "100"),
+            Self::SpecialBlack => write!(f, "110"),
+        }
+    }
+}
+
+impl Color {
+    /// Returns the structured representation of the code of this color.
+    pub fn copic_code(&self) -> CopicCode {
+        self.code
+            .parse()
+            .unwrap_or_else(|e| unreachable!("invalid built-in code {:?}: {e}", self.code))
+    }
+}
+
+/// Tone number of a gray color (the "5" in "N-5").
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum GrayTone {
+    T00,
+    T0,
+    T1,
+    T2,
+    T3,
+    T4,
+    T5,
+    T6,
+    T7,
+    T8,
+    T9,
+    T10,
+}
+
+impl GrayTone {
+    /// Returns the string representation of this tone (e.g., "00", "5").
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::T00 => "00",
+            Self::T0 => "0",
+            Self::T1 => "1",
+            Self::T2 => "2",
+            Self::T3 => "3",
+            Self::T4 => "4",
+            Self::T5 => "5",
+            Self::T6 => "6",
+            Self::T7 => "7",
+            Self::T8 => "8",
+            Self::T9 => "9",
+            Self::T10 => "10",
+        }
+    }
+
+    fn from_digits(s: &[u8]) -> Option<Self> {
+        Some(match s {
+            b"00" => Self::T00,
+            b"0" => Self::T0,
+            b"1" => Self::T1,
+            b"2" => Self::T2,
+            b"3" => Self::T3,
+            b"4" => Self::T4,
+            b"5" => Self::T5,
+            b"6" => Self::T6,
+            b"7" => Self::T7,
+            b"8" => Self::T8,
+            b"9" => Self::T9,
+            b"10" => Self::T10,
+            _ => return None,
+        })
+    }
+}
+
+/// Error returned when parsing a [`CopicCode`] fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseCodeError {
+    /// The input string is empty.
+    Empty,
+
+    /// The input string is too long to be a color code.
+    TooLong,
+
+    /// The input string does not start with a known family prefix.
+    UnknownPrefix,
+
+    /// The characters following the family prefix are not a valid group, value or tone.
+    InvalidSuffix,
+}
+
+impl core::fmt::Display for ParseCodeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty color code"),
+            Self::TooLong => write!(f, "color code is too long"),
+            Self::UnknownPrefix => write!(f, "unknown color family prefix"),
+            Self::InvalidSuffix => write!(f, "invalid group, value or tone digits"),
+        }
+    }
+}
+
+impl core::error::Error for ParseCodeError {}
+
+const HUE_FAMILIES: [Family; 11] = [
+    Family::BlueViolet,
+    Family::Violet,
+    Family::RedViolet,
+    Family::Red,
+    Family::YellowRed,
+    Family::Yellow,
+    Family::YellowGreen,
+    Family::Green,
+    Family::BlueGreen,
+    Family::Blue,
+    Family::Earth,
+];
+
+const GRAY_FAMILIES: [Family; 4] = [
+    Family::CoolGray,
+    Family::NeutralGray,
+    Family::TonerGray,
+    Family::WarmGray,
+];
+
+const fn hue_prefix(family: Family) -> Option<&'static str> {
+    Some(match family {
+        Family::BlueViolet => "BV",
+        Family::Violet => "V",
+        Family::RedViolet => "RV",
+        Family::Red => "R",
+        Family::YellowRed => "YR",
+        Family::Yellow => "Y",
+        Family::YellowGreen => "YG",
+        Family::Green => "G",
+        Family::BlueGreen => "BG",
+        Family::Blue => "B",
+        Family::Earth => "E",
+        _ => return None,
+    })
+}
+
+const fn gray_prefix(family: Family) -> Option<&'static str> {
+    Some(match family {
+        Family::CoolGray => "C",
+        Family::NeutralGray => "N",
+        Family::TonerGray => "T",
+        Family::WarmGray => "W",
+        _ => return None,
+    })
+}
+
+const fn fluorescent_hue_prefix(family: Family) -> Option<&'static str> {
+    match family {
+        Family::BlueViolet | Family::Red | Family::Earth => None,
+        _ => hue_prefix(family),
+    }
+}
+
+fn strip_family_prefix(
+    s: &[u8],
+    prefix: fn(Family) -> Option<&'static str>,
+) -> Option<(Family, &[u8])> {
+    // Tries longer prefixes first so that "BV" is not mistaken for "B".
+    HUE_FAMILIES
+        .iter()
+        .chain(GRAY_FAMILIES.iter())
+        .filter_map(|&family| prefix(family).map(|p| (family, p)))
+        .filter(|(_, p)| s.starts_with(p.as_bytes()))
+        .max_by_key(|(_, p)| p.len())
+        .map(|(family, p)| (family, &s[p.len()..]))
+}
+
+const fn is_valid_group_value(group: Group, value: Value) -> bool {
+    match (group, value) {
+        (Group::Undefined, _) | (_, Value::Undefined) => false,
+        (Group::S0, _) => true,
+        (_, Value::B000 | Value::B00) => false,
+        _ => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ALL_COLORS;
+    use std::string::ToString;
+
+    #[test]
+    fn all_codes_round_trip() {
+        for color in &ALL_COLORS {
+            assert_eq!(color.copic_code().to_string(), color.code);
+        }
+    }
+}
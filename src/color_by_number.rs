@@ -0,0 +1,634 @@
+//! Color-by-number template generation.
+//!
+//! A [`Template`] is built from a [`Quantized`] image (typically made with [`Dithering::None`](crate::image::Dithering::None),
+//! as dithering produces many tiny regions) and can be written as an SVG with region outlines,
+//! code labels and a legend.
+use crate::image::Quantized;
+use crate::Color;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+
+/// Color-by-number template made of connected regions of the same color.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template<'a> {
+    width: usize,
+    height: usize,
+    regions: Vec<Region<'a>>,
+    region_map: Vec<u32>,
+}
+
+impl<'a> Template<'a> {
+    /// Makes a template from `image`.
+    ///
+    /// Regions (4-connected pixels having the same color) smaller than `min_region_pixels` are merged
+    /// into the neighboring region sharing the longest border with them.
+    pub fn new(image: &Quantized<'a>, min_region_pixels: usize) -> Self {
+        const MAX_MERGE_ROUNDS: usize = 32;
+
+        let (width, height) = (image.width(), image.height());
+        let mut indices = image.indices().to_vec();
+        let mut components = Components::new(&indices, width, height);
+        for _ in 0..MAX_MERGE_ROUNDS {
+            let mut merged = false;
+            let mut small = (0..components.sizes.len())
+                .filter(|&r| components.sizes[r] < min_region_pixels)
+                .collect::<Vec<_>>();
+            small.sort_by_key(|&r| components.sizes[r]);
+            for r in small {
+                if let Some(index) = dominant_neighbor(&components, &indices, r, width, height) {
+                    for &i in components.pixels(r) {
+                        indices[i] = index;
+                    }
+                    merged = true;
+                }
+            }
+            if !merged {
+                break;
+            }
+            components = Components::new(&indices, width, height);
+        }
+
+        let positions = label_positions(&components.map, components.sizes.len(), width, height);
+        let regions = positions
+            .into_iter()
+            .enumerate()
+            .map(|(r, label_position)| Region {
+                color: image.palette()[usize::from(indices[components.first_pixels[r]])],
+                pixels: components.sizes[r],
+                label_position,
+            })
+            .collect();
+        Self {
+            width,
+            height,
+            regions,
+            region_map: components.map,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the regions in the order of their first pixel (in row-major order).
+    pub fn regions(&self) -> &[Region<'a>] {
+        &self.regions
+    }
+
+    /// Returns the row-major indices into [`Template::regions()`] of the pixels.
+    pub fn region_map(&self) -> &[u32] {
+        &self.region_map
+    }
+
+    /// Returns the region containing the pixel at `(x, y)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `(x, y)` is out of bounds.
+    pub fn region_at(&self, x: usize, y: usize) -> &Region<'a> {
+        assert!(x < self.width && y < self.height, "out of bounds");
+        &self.regions[self.region_map[y * self.width + x] as usize]
+    }
+
+    /// Returns the distinct colors used by the regions, sorted by code.
+    pub fn legend(&self) -> Vec<&'a Color> {
+        let mut colors = self.regions.iter().map(|r| r.color).collect::<Vec<_>>();
+        colors.sort_by_key(|c| c.code);
+        colors.dedup_by_key(|c| c.code);
+        colors
+    }
+
+    /// Writes this template as an SVG document.
+    pub fn write_svg<W: Write>(&self, mut writer: W, options: &SvgOptions) -> fmt::Result {
+        let scale = options.pixel_size;
+        let image_width = self.width as f32 * scale;
+        let image_height = self.height as f32 * scale;
+        let legend = if options.legend {
+            self.legend()
+        } else {
+            Vec::new()
+        };
+        let legend_columns = ((image_width / LEGEND_COLUMN_WIDTH) as usize).max(1);
+        let legend_rows = legend.len().div_ceil(legend_columns);
+        let legend_height = if legend.is_empty() {
+            0.0
+        } else {
+            LEGEND_MARGIN + legend_rows as f32 * LEGEND_ROW_HEIGHT
+        };
+        let svg_width = image_width.max(if legend.is_empty() {
+            0.0
+        } else {
+            LEGEND_COLUMN_WIDTH
+        });
+
+        writeln!(
+            writer,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{svg_width}" height="{}" viewBox="0 0 {svg_width} {}">"#,
+            image_height + legend_height,
+            image_height + legend_height,
+        )?;
+        writeln!(
+            writer,
+            r#"<rect width="{svg_width}" height="{}" fill="white"/>"#,
+            image_height + legend_height
+        )?;
+
+        if options.fill {
+            // One rectangle per horizontal run of pixels belonging to the same region.
+            writeln!(writer, r#"<g shape-rendering="crispEdges">"#)?;
+            for y in 0..self.height {
+                let row = &self.region_map[y * self.width..][..self.width];
+                let mut x = 0;
+                while x < self.width {
+                    let run = row[x..].iter().take_while(|&&r| r == row[x]).count();
+                    writeln!(
+                        writer,
+                        r#"<rect x="{}" y="{}" width="{}" height="{scale}" fill="{}"/>"#,
+                        x as f32 * scale,
+                        y as f32 * scale,
+                        run as f32 * scale,
+                        self.regions[row[x] as usize].color.rgb,
+                    )?;
+                    x += run;
+                }
+            }
+            writeln!(writer, "</g>")?;
+        }
+
+        // Outlines: horizontal and vertical edges between different regions (and the image border),
+        // with consecutive edges joined into a single segment.
+        write!(
+            writer,
+            r#"<path fill="none" stroke="black" stroke-width="{}" d=""#,
+            options.stroke_width
+        )?;
+        for y in 0..=self.height {
+            let mut start = None;
+            for x in 0..=self.width {
+                let edge = x < self.width && self.is_edge((x, y), (x, y.wrapping_sub(1)));
+                match (edge, start) {
+                    (true, None) => start = Some(x),
+                    (false, Some(x0)) => {
+                        write!(
+                            writer,
+                            "M{} {}H{}",
+                            x0 as f32 * scale,
+                            y as f32 * scale,
+                            x as f32 * scale
+                        )?;
+                        start = None;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        for x in 0..=self.width {
+            let mut start = None;
+            for y in 0..=self.height {
+                let edge = y < self.height && self.is_edge((x, y), (x.wrapping_sub(1), y));
+                match (edge, start) {
+                    (true, None) => start = Some(y),
+                    (false, Some(y0)) => {
+                        write!(
+                            writer,
+                            "M{} {}V{}",
+                            x as f32 * scale,
+                            y0 as f32 * scale,
+                            y as f32 * scale
+                        )?;
+                        start = None;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        writeln!(writer, r#""/>"#)?;
+
+        writeln!(
+            writer,
+            r#"<g font-family="sans-serif" font-size="{}" text-anchor="middle" dominant-baseline="central">"#,
+            options.font_size
+        )?;
+        for region in &self.regions {
+            let (x, y) = region.label_position;
+            writeln!(
+                writer,
+                r#"<text x="{}" y="{}">{}</text>"#,
+                (x as f32 + 0.5) * scale,
+                (y as f32 + 0.5) * scale,
+                XmlEscape(region.color.code)
+            )?;
+        }
+        writeln!(writer, "</g>")?;
+
+        if !legend.is_empty() {
+            writeln!(writer, r#"<g font-family="sans-serif" font-size="12">"#)?;
+            for (i, color) in legend.iter().enumerate() {
+                let x = (i % legend_columns) as f32 * LEGEND_COLUMN_WIDTH;
+                let y =
+                    image_height + LEGEND_MARGIN + (i / legend_columns) as f32 * LEGEND_ROW_HEIGHT;
+                writeln!(
+                    writer,
+                    r#"<rect x="{}" y="{}" width="16" height="16" fill="{}" stroke="black"/>"#,
+                    x + 4.0,
+                    y,
+                    color.rgb
+                )?;
+                writeln!(
+                    writer,
+                    r#"<text x="{}" y="{}" dominant-baseline="central">{} {}</text>"#,
+                    x + 26.0,
+                    y + 8.0,
+                    XmlEscape(color.code),
+                    XmlEscape(color.name)
+                )?;
+            }
+            writeln!(writer, "</g>")?;
+        }
+        writeln!(writer, "</svg>")
+    }
+
+    /// Returns `true` if there is an outline between the pixels `a` (inside the image) and `b`.
+    fn is_edge(&self, a: (usize, usize), b: (usize, usize)) -> bool {
+        if a.0 >= self.width || a.1 >= self.height {
+            return b.0 < self.width && b.1 < self.height;
+        }
+        if b.0 >= self.width || b.1 >= self.height {
+            return true;
+        }
+        self.region_map[a.1 * self.width + a.0] != self.region_map[b.1 * self.width + b.0]
+    }
+}
+
+/// Connected region of a [`Template`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Region<'a> {
+    pub color: &'a Color,
+
+    /// Number of pixels in this region.
+    pub pixels: usize,
+
+    /// Pixel where the label should be placed (the pixel farthest from the region's border).
+    pub label_position: (usize, usize),
+}
+
+/// Options for [`Template::write_svg()`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SvgOptions {
+    /// Size of an image pixel in SVG user units.
+    pub pixel_size: f32,
+
+    /// Width of the region outlines.
+    pub stroke_width: f32,
+
+    /// Font size of the code labels.
+    pub font_size: f32,
+
+    /// If `true`, regions are filled with their colors (useful for previews).
+    pub fill: bool,
+
+    /// If `true`, a legend listing the code, name and swatch of each color is written below the image.
+    pub legend: bool,
+}
+
+impl Default for SvgOptions {
+    fn default() -> Self {
+        Self {
+            pixel_size: 4.0,
+            stroke_width: 0.5,
+            font_size: 6.0,
+            fill: false,
+            legend: true,
+        }
+    }
+}
+
+const LEGEND_MARGIN: f32 = 16.0;
+const LEGEND_ROW_HEIGHT: f32 = 24.0;
+const LEGEND_COLUMN_WIDTH: f32 = 200.0;
+
+/// 4-connected components of an image.
+struct Components {
+    map: Vec<u32>,
+    sizes: Vec<usize>,
+    first_pixels: Vec<usize>,
+
+    /// Pixels grouped by component (the pixels of the component `r` start at `offsets[r]`).
+    pixels: Vec<usize>,
+    offsets: Vec<usize>,
+}
+
+impl Components {
+    fn new(indices: &[u16], width: usize, height: usize) -> Self {
+        let mut map = alloc::vec![u32::MAX; indices.len()];
+        let mut sizes = Vec::new();
+        let mut first_pixels = Vec::new();
+        let mut stack = Vec::new();
+        for start in 0..indices.len() {
+            if map[start] != u32::MAX {
+                continue;
+            }
+            let region = sizes.len() as u32;
+            let mut size = 0;
+            map[start] = region;
+            stack.push(start);
+            while let Some(i) = stack.pop() {
+                size += 1;
+                for j in neighbors(i, width, height) {
+                    if map[j] == u32::MAX && indices[j] == indices[start] {
+                        map[j] = region;
+                        stack.push(j);
+                    }
+                }
+            }
+            sizes.push(size);
+            first_pixels.push(start);
+        }
+
+        let mut offsets = Vec::with_capacity(sizes.len() + 1);
+        offsets.push(0);
+        for size in &sizes {
+            offsets.push(offsets[offsets.len() - 1] + size);
+        }
+        let mut next = offsets.clone();
+        let mut pixels = alloc::vec![0; indices.len()];
+        for (i, &r) in map.iter().enumerate() {
+            pixels[next[r as usize]] = i;
+            next[r as usize] += 1;
+        }
+        Self {
+            map,
+            sizes,
+            first_pixels,
+            pixels,
+            offsets,
+        }
+    }
+
+    fn pixels(&self, r: usize) -> &[usize] {
+        &self.pixels[self.offsets[r]..self.offsets[r + 1]]
+    }
+}
+
+/// Returns the palette index that shares the longest border with the region `r`.
+fn dominant_neighbor(
+    components: &Components,
+    indices: &[u16],
+    r: usize,
+    width: usize,
+    height: usize,
+) -> Option<u16> {
+    let mut counts = Vec::<(u16, usize)>::new();
+    for &i in components.pixels(r) {
+        for j in neighbors(i, width, height) {
+            if components.map[j] as usize == r {
+                continue;
+            }
+            match counts.iter_mut().find(|(index, _)| *index == indices[j]) {
+                Some((_, n)) => *n += 1,
+                None => counts.push((indices[j], 1)),
+            }
+        }
+    }
+    counts
+        .into_iter()
+        .max_by_key(|&(_, n)| n)
+        .map(|(index, _)| index)
+}
+
+/// Returns, for each region, the pixel farthest (in chessboard distance) from the region's border.
+fn label_positions(
+    map: &[u32],
+    regions: usize,
+    width: usize,
+    height: usize,
+) -> Vec<(usize, usize)> {
+    let mut distances = (0..map.len())
+        .map(|i| {
+            let (x, y) = (i % width, i / width);
+            let inner = x > 0
+                && y > 0
+                && x + 1 < width
+                && y + 1 < height
+                && neighbors(i, width, height).all(|j| map[j] == map[i]);
+            if inner {
+                u32::MAX
+            } else {
+                0
+            }
+        })
+        .collect::<Vec<_>>();
+
+    // Two-pass chamfer distance transform (restricted to the same region).
+    let mut relax = |i: usize, j: usize| {
+        if map[i] == map[j] {
+            distances[i] = distances[i].min(distances[j].saturating_add(1));
+        }
+    };
+    for y in 0..height {
+        for x in 0..width {
+            let i = y * width + x;
+            if y > 0 {
+                relax(i, i - width);
+                if x > 0 {
+                    relax(i, i - width - 1);
+                }
+                if x + 1 < width {
+                    relax(i, i - width + 1);
+                }
+            }
+            if x > 0 {
+                relax(i, i - 1);
+            }
+        }
+    }
+    for y in (0..height).rev() {
+        for x in (0..width).rev() {
+            let i = y * width + x;
+            if y + 1 < height {
+                relax(i, i + width);
+                if x > 0 {
+                    relax(i, i + width - 1);
+                }
+                if x + 1 < width {
+                    relax(i, i + width + 1);
+                }
+            }
+            if x + 1 < width {
+                relax(i, i + 1);
+            }
+        }
+    }
+
+    let mut best = alloc::vec![(0, None); regions];
+    for (i, (&r, &d)) in map.iter().zip(&distances).enumerate() {
+        let (best_d, best_i) = &mut best[r as usize];
+        if best_i.is_none() || d > *best_d {
+            *best_d = d;
+            *best_i = Some(i);
+        }
+    }
+    best.into_iter()
+        .map(|(_, i)| {
+            let i = i.unwrap_or_else(|| unreachable!());
+            (i % width, i / width)
+        })
+        .collect()
+}
+
+fn neighbors(i: usize, width: usize, height: usize) -> impl Iterator<Item = usize> {
+    let (x, y) = (i % width, i / width);
+    [
+        (x > 0).then(|| i - 1),
+        (x + 1 < width).then(|| i + 1),
+        (y > 0).then(|| i - width),
+        (y + 1 < height).then(|| i + width),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+struct XmlEscape<'a>(&'a str);
+
+impl fmt::Display for XmlEscape<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '&' => f.write_str("&amp;")?,
+                '<' => f.write_str("&lt;")?,
+                '>' => f.write_str("&gt;")?,
+                '"' => f.write_str("&quot;")?,
+                '\'' => f.write_str("&apos;")?,
+                _ => f.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::image::{quantize, Dithering};
+    use crate::{COLOR_BV04, COLOR_R29, COLOR_Y11};
+    use std::format;
+    use std::string::String;
+
+    const PALETTE: [&Color; 3] = [&COLOR_BV04, &COLOR_Y11, &COLOR_R29];
+    const WIDTH: usize = 8;
+    const HEIGHT: usize = 6;
+
+    /// Two halves (BV04 and Y11) with a 1-pixel and a 2-pixel spot of R29.
+    fn image() -> Quantized<'static> {
+        const PIXELS: &str = "
+            AAAABBBB
+            ACAABBBB
+            AAAABBBB
+            AAAABBBB
+            AAAABBCB
+            AAAABBCB";
+        let rgb = PIXELS
+            .bytes()
+            .filter(u8::is_ascii_uppercase)
+            .flat_map(|b| {
+                let rgb = PALETTE[usize::from(b - b'A')].rgb;
+                [rgb.r, rgb.g, rgb.b]
+            })
+            .collect::<Vec<_>>();
+        quantize(&rgb, WIDTH, HEIGHT, PALETTE, Dithering::None).unwrap()
+    }
+
+    fn codes(colors: impl IntoIterator<Item = &'static Color>) -> Vec<&'static str> {
+        colors.into_iter().map(|c| c.code).collect()
+    }
+
+    #[test]
+    fn regions() {
+        let template = Template::new(&image(), 1);
+        assert_eq!((template.width(), template.height()), (WIDTH, HEIGHT));
+        assert_eq!(
+            template
+                .regions()
+                .iter()
+                .map(|r| (r.color.code, r.pixels))
+                .collect::<Vec<_>>(),
+            [("BV04", 23), ("Y11", 22), ("R29", 1), ("R29", 2)]
+        );
+        assert_eq!(template.region_at(1, 1), &template.regions()[2]);
+        assert_eq!(template.region_at(6, 5), &template.regions()[3]);
+        assert_eq!(template.region_at(7, 5).color, &COLOR_Y11);
+        assert_eq!(template.region_map().len(), WIDTH * HEIGHT);
+        assert_eq!(codes(template.legend()), ["BV04", "R29", "Y11"]);
+
+        for region in template.regions() {
+            let (x, y) = region.label_position;
+            assert_eq!(template.region_at(x, y), region);
+        }
+    }
+
+    #[test]
+    fn small_regions_are_merged() {
+        for min_region_pixels in [2, 3, 10] {
+            let template = Template::new(&image(), min_region_pixels);
+            assert!(template
+                .regions()
+                .iter()
+                .all(|r| r.pixels >= min_region_pixels));
+        }
+
+        let template = Template::new(&image(), 3);
+        assert_eq!(
+            template
+                .regions()
+                .iter()
+                .map(|r| (r.color.code, r.pixels))
+                .collect::<Vec<_>>(),
+            [("BV04", 24), ("Y11", 24)]
+        );
+        assert_eq!(template.region_at(1, 1).color, &COLOR_BV04);
+        assert_eq!(template.region_at(6, 4).color, &COLOR_Y11);
+        assert_eq!(codes(template.legend()), ["BV04", "Y11"]);
+    }
+
+    #[test]
+    fn svg() {
+        let template = Template::new(&image(), 1);
+
+        let mut svg = String::new();
+        template
+            .write_svg(&mut svg, &SvgOptions::default())
+            .unwrap();
+        assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" "));
+        assert!(svg.ends_with("</svg>\n"));
+        assert_eq!(svg.matches("<path ").count(), 1);
+        assert_eq!(svg.matches(">R29</text>").count(), 2);
+        assert_eq!(svg.matches(">BV04</text>").count(), 1);
+        assert_eq!(svg.matches(">BV04 Blue Berry</text>").count(), 1);
+        assert_eq!(
+            svg.matches("<text ").count(),
+            template.regions().len() + template.legend().len()
+        );
+        assert!(!svg.contains("shape-rendering"));
+
+        let options = SvgOptions {
+            fill: true,
+            legend: false,
+            ..SvgOptions::default()
+        };
+        let mut svg = String::new();
+        template.write_svg(&mut svg, &options).unwrap();
+        assert!(!svg.contains("Blue Berry"));
+        assert_eq!(svg.matches("<text ").count(), template.regions().len());
+        // Rows without an R29 spot have 2 runs, and the others have 4.
+        assert_eq!(svg.matches("<rect x=").count(), 3 * 2 + 3 * 4);
+        assert_eq!(
+            svg.matches(&format!(r#"fill="{}""#, COLOR_R29.rgb)).count(),
+            3
+        );
+    }
+}
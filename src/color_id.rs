@@ -0,0 +1,1226 @@
+use crate::{Color, ALL_COLORS};
+
+const COLORS: &[Color] = &ALL_COLORS;
+
+/// Number of assigned [`ColorId`]s.
+const ID_COUNT: usize = ColorId::ALL.len();
+
+/// Compact and stable identifier of a color, suitable for save files and network protocols.
+///
+/// Each variant is named after the corresponding `COLOR_*` constant (e.g., [`ColorId::BV04`] for
+/// [`COLOR_BV04`](crate::COLOR_BV04)), except that "0", "100" and "110" are named
+/// [`ColorId::Blender`], [`ColorId::Black`] and [`ColorId::SpecialBlack`] as in [`CopicCode`](crate::CopicCode).
+///
+/// The ID (i.e., `id as u16`) of an existing color never changes across releases.
+/// The initial IDs equal the positions of the colors in [`ALL_COLORS`] at the time `ColorId` was introduced;
+/// colors added later get new IDs after the existing ones, regardless of their positions in [`ALL_COLORS`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[allow(non_camel_case_types)]
+#[repr(u16)]
+pub enum ColorId {
+    BV0000 = 0,
+    BV000 = 1,
+    BV00 = 2,
+    BV01 = 3,
+    BV02 = 4,
+    BV04 = 5,
+    BV08 = 6,
+    BV11 = 7,
+    BV13 = 8,
+    BV17 = 9,
+    BV20 = 10,
+    BV23 = 11,
+    BV25 = 12,
+    BV29 = 13,
+    BV31 = 14,
+    BV34 = 15,
+    V0000 = 16,
+    V000 = 17,
+    V01 = 18,
+    V04 = 19,
+    V05 = 20,
+    V06 = 21,
+    V09 = 22,
+    V12 = 23,
+    V15 = 24,
+    V17 = 25,
+    V20 = 26,
+    V22 = 27,
+    V25 = 28,
+    V28 = 29,
+    V91 = 30,
+    V93 = 31,
+    V95 = 32,
+    V99 = 33,
+    RV0000 = 34,
+    RV000 = 35,
+    RV00 = 36,
+    RV02 = 37,
+    RV04 = 38,
+    RV06 = 39,
+    RV09 = 40,
+    RV10 = 41,
+    RV11 = 42,
+    RV13 = 43,
+    RV14 = 44,
+    RV17 = 45,
+    RV19 = 46,
+    RV21 = 47,
+    RV23 = 48,
+    RV25 = 49,
+    RV29 = 50,
+    RV32 = 51,
+    RV34 = 52,
+    RV42 = 53,
+    RV52 = 54,
+    RV55 = 55,
+    RV63 = 56,
+    RV66 = 57,
+    RV69 = 58,
+    RV91 = 59,
+    RV93 = 60,
+    RV95 = 61,
+    RV99 = 62,
+    R0000 = 63,
+    R000 = 64,
+    R00 = 65,
+    R01 = 66,
+    R02 = 67,
+    R05 = 68,
+    R08 = 69,
+    R11 = 70,
+    R12 = 71,
+    R14 = 72,
+    R17 = 73,
+    R20 = 74,
+    R21 = 75,
+    R22 = 76,
+    R24 = 77,
+    R27 = 78,
+    R29 = 79,
+    R30 = 80,
+    R32 = 81,
+    R35 = 82,
+    R37 = 83,
+    R39 = 84,
+    R43 = 85,
+    R46 = 86,
+    R56 = 87,
+    R59 = 88,
+    R81 = 89,
+    R83 = 90,
+    R85 = 91,
+    R89 = 92,
+    YR0000 = 93,
+    YR000 = 94,
+    YR00 = 95,
+    YR01 = 96,
+    YR02 = 97,
+    YR04 = 98,
+    YR07 = 99,
+    YR09 = 100,
+    YR12 = 101,
+    YR14 = 102,
+    YR15 = 103,
+    YR16 = 104,
+    YR18 = 105,
+    YR20 = 106,
+    YR21 = 107,
+    YR23 = 108,
+    YR24 = 109,
+    YR27 = 110,
+    YR30 = 111,
+    YR31 = 112,
+    YR61 = 113,
+    YR65 = 114,
+    YR68 = 115,
+    YR82 = 116,
+    Y0000 = 117,
+    Y000 = 118,
+    Y00 = 119,
+    Y02 = 120,
+    Y04 = 121,
+    Y06 = 122,
+    Y08 = 123,
+    Y11 = 124,
+    Y13 = 125,
+    Y15 = 126,
+    Y17 = 127,
+    Y18 = 128,
+    Y19 = 129,
+    Y21 = 130,
+    Y23 = 131,
+    Y26 = 132,
+    Y28 = 133,
+    Y32 = 134,
+    Y35 = 135,
+    Y38 = 136,
+    YG0000 = 137,
+    YG00 = 138,
+    YG01 = 139,
+    YG03 = 140,
+    YG05 = 141,
+    YG06 = 142,
+    YG07 = 143,
+    YG09 = 144,
+    YG11 = 145,
+    YG13 = 146,
+    YG17 = 147,
+    YG21 = 148,
+    YG23 = 149,
+    YG25 = 150,
+    YG41 = 151,
+    YG45 = 152,
+    YG61 = 153,
+    YG63 = 154,
+    YG67 = 155,
+    YG91 = 156,
+    YG93 = 157,
+    YG95 = 158,
+    YG97 = 159,
+    YG99 = 160,
+    G0000 = 161,
+    G000 = 162,
+    G00 = 163,
+    G02 = 164,
+    G03 = 165,
+    G05 = 166,
+    G07 = 167,
+    G09 = 168,
+    G12 = 169,
+    G14 = 170,
+    G16 = 171,
+    G17 = 172,
+    G19 = 173,
+    G20 = 174,
+    G21 = 175,
+    G24 = 176,
+    G28 = 177,
+    G29 = 178,
+    G40 = 179,
+    G43 = 180,
+    G46 = 181,
+    G82 = 182,
+    G85 = 183,
+    G94 = 184,
+    G99 = 185,
+    BG0000 = 186,
+    BG000 = 187,
+    BG01 = 188,
+    BG02 = 189,
+    BG05 = 190,
+    BG07 = 191,
+    BG09 = 192,
+    BG10 = 193,
+    BG11 = 194,
+    BG13 = 195,
+    BG15 = 196,
+    BG18 = 197,
+    BG23 = 198,
+    BG32 = 199,
+    BG34 = 200,
+    BG45 = 201,
+    BG49 = 202,
+    BG53 = 203,
+    BG57 = 204,
+    BG70 = 205,
+    BG72 = 206,
+    BG75 = 207,
+    BG78 = 208,
+    BG90 = 209,
+    BG93 = 210,
+    BG96 = 211,
+    BG99 = 212,
+    B0000 = 213,
+    B000 = 214,
+    B00 = 215,
+    B01 = 216,
+    B02 = 217,
+    B04 = 218,
+    B05 = 219,
+    B06 = 220,
+    B12 = 221,
+    B14 = 222,
+    B16 = 223,
+    B18 = 224,
+    B21 = 225,
+    B23 = 226,
+    B24 = 227,
+    B26 = 228,
+    B28 = 229,
+    B29 = 230,
+    B32 = 231,
+    B34 = 232,
+    B37 = 233,
+    B39 = 234,
+    B41 = 235,
+    B45 = 236,
+    B52 = 237,
+    B60 = 238,
+    B63 = 239,
+    B66 = 240,
+    B69 = 241,
+    B79 = 242,
+    B91 = 243,
+    B93 = 244,
+    B95 = 245,
+    B97 = 246,
+    B99 = 247,
+    E0000 = 248,
+    E000 = 249,
+    E00 = 250,
+    E01 = 251,
+    E02 = 252,
+    E04 = 253,
+    E07 = 254,
+    E08 = 255,
+    E09 = 256,
+    E11 = 257,
+    E13 = 258,
+    E15 = 259,
+    E17 = 260,
+    E18 = 261,
+    E19 = 262,
+    E21 = 263,
+    E23 = 264,
+    E25 = 265,
+    E27 = 266,
+    E29 = 267,
+    E30 = 268,
+    E31 = 269,
+    E33 = 270,
+    E34 = 271,
+    E35 = 272,
+    E37 = 273,
+    E39 = 274,
+    E40 = 275,
+    E41 = 276,
+    E42 = 277,
+    E43 = 278,
+    E44 = 279,
+    E47 = 280,
+    E49 = 281,
+    E50 = 282,
+    E51 = 283,
+    E53 = 284,
+    E55 = 285,
+    E57 = 286,
+    E59 = 287,
+    E70 = 288,
+    E71 = 289,
+    E74 = 290,
+    E77 = 291,
+    E79 = 292,
+    E81 = 293,
+    E84 = 294,
+    E87 = 295,
+    E89 = 296,
+    E93 = 297,
+    E95 = 298,
+    E97 = 299,
+    E99 = 300,
+    C_00 = 301,
+    C_0 = 302,
+    C_1 = 303,
+    C_2 = 304,
+    C_3 = 305,
+    C_4 = 306,
+    C_5 = 307,
+    C_6 = 308,
+    C_7 = 309,
+    C_8 = 310,
+    C_9 = 311,
+    C_10 = 312,
+    N_0 = 313,
+    N_1 = 314,
+    N_2 = 315,
+    N_3 = 316,
+    N_4 = 317,
+    N_5 = 318,
+    N_6 = 319,
+    N_7 = 320,
+    N_8 = 321,
+    N_9 = 322,
+    N_10 = 323,
+    T_0 = 324,
+    T_1 = 325,
+    T_2 = 326,
+    T_3 = 327,
+    T_4 = 328,
+    T_5 = 329,
+    T_6 = 330,
+    T_7 = 331,
+    T_8 = 332,
+    T_9 = 333,
+    T_10 = 334,
+    W_00 = 335,
+    W_0 = 336,
+    W_1 = 337,
+    W_2 = 338,
+    W_3 = 339,
+    W_4 = 340,
+    W_5 = 341,
+    W_6 = 342,
+    W_7 = 343,
+    W_8 = 344,
+    W_9 = 345,
+    W_10 = 346,
+    Blender = 347,
+    Black = 348,
+    SpecialBlack = 349,
+    FV = 350,
+    FRV = 351,
+    FYR = 352,
+    FY = 353,
+    FYG = 354,
+    FG = 355,
+    FBG = 356,
+    FB = 357,
+}
+
+impl ColorId {
+    /// All IDs in the order of [`ALL_COLORS`].
+    pub const ALL: [Self; 358] = [
+        Self::BV0000,
+        Self::BV000,
+        Self::BV00,
+        Self::BV01,
+        Self::BV02,
+        Self::BV04,
+        Self::BV08,
+        Self::BV11,
+        Self::BV13,
+        Self::BV17,
+        Self::BV20,
+        Self::BV23,
+        Self::BV25,
+        Self::BV29,
+        Self::BV31,
+        Self::BV34,
+        Self::V0000,
+        Self::V000,
+        Self::V01,
+        Self::V04,
+        Self::V05,
+        Self::V06,
+        Self::V09,
+        Self::V12,
+        Self::V15,
+        Self::V17,
+        Self::V20,
+        Self::V22,
+        Self::V25,
+        Self::V28,
+        Self::V91,
+        Self::V93,
+        Self::V95,
+        Self::V99,
+        Self::RV0000,
+        Self::RV000,
+        Self::RV00,
+        Self::RV02,
+        Self::RV04,
+        Self::RV06,
+        Self::RV09,
+        Self::RV10,
+        Self::RV11,
+        Self::RV13,
+        Self::RV14,
+        Self::RV17,
+        Self::RV19,
+        Self::RV21,
+        Self::RV23,
+        Self::RV25,
+        Self::RV29,
+        Self::RV32,
+        Self::RV34,
+        Self::RV42,
+        Self::RV52,
+        Self::RV55,
+        Self::RV63,
+        Self::RV66,
+        Self::RV69,
+        Self::RV91,
+        Self::RV93,
+        Self::RV95,
+        Self::RV99,
+        Self::R0000,
+        Self::R000,
+        Self::R00,
+        Self::R01,
+        Self::R02,
+        Self::R05,
+        Self::R08,
+        Self::R11,
+        Self::R12,
+        Self::R14,
+        Self::R17,
+        Self::R20,
+        Self::R21,
+        Self::R22,
+        Self::R24,
+        Self::R27,
+        Self::R29,
+        Self::R30,
+        Self::R32,
+        Self::R35,
+        Self::R37,
+        Self::R39,
+        Self::R43,
+        Self::R46,
+        Self::R56,
+        Self::R59,
+        Self::R81,
+        Self::R83,
+        Self::R85,
+        Self::R89,
+        Self::YR0000,
+        Self::YR000,
+        Self::YR00,
+        Self::YR01,
+        Self::YR02,
+        Self::YR04,
+        Self::YR07,
+        Self::YR09,
+        Self::YR12,
+        Self::YR14,
+        Self::YR15,
+        Self::YR16,
+        Self::YR18,
+        Self::YR20,
+        Self::YR21,
+        Self::YR23,
+        Self::YR24,
+        Self::YR27,
+        Self::YR30,
+        Self::YR31,
+        Self::YR61,
+        Self::YR65,
+        Self::YR68,
+        Self::YR82,
+        Self::Y0000,
+        Self::Y000,
+        Self::Y00,
+        Self::Y02,
+        Self::Y04,
+        Self::Y06,
+        Self::Y08,
+        Self::Y11,
+        Self::Y13,
+        Self::Y15,
+        Self::Y17,
+        Self::Y18,
+        Self::Y19,
+        Self::Y21,
+        Self::Y23,
+        Self::Y26,
+        Self::Y28,
+        Self::Y32,
+        Self::Y35,
+        Self::Y38,
+        Self::YG0000,
+        Self::YG00,
+        Self::YG01,
+        Self::YG03,
+        Self::YG05,
+        Self::YG06,
+        Self::YG07,
+        Self::YG09,
+        Self::YG11,
+        Self::YG13,
+        Self::YG17,
+        Self::YG21,
+        Self::YG23,
+        Self::YG25,
+        Self::YG41,
+        Self::YG45,
+        Self::YG61,
+        Self::YG63,
+        Self::YG67,
+        Self::YG91,
+        Self::YG93,
+        Self::YG95,
+        Self::YG97,
+        Self::YG99,
+        Self::G0000,
+        Self::G000,
+        Self::G00,
+        Self::G02,
+        Self::G03,
+        Self::G05,
+        Self::G07,
+        Self::G09,
+        Self::G12,
+        Self::G14,
+        Self::G16,
+        Self::G17,
+        Self::G19,
+        Self::G20,
+        Self::G21,
+        Self::G24,
+        Self::G28,
+        Self::G29,
+        Self::G40,
+        Self::G43,
+        Self::G46,
+        Self::G82,
+        Self::G85,
+        Self::G94,
+        Self::G99,
+        Self::BG0000,
+        Self::BG000,
+        Self::BG01,
+        Self::BG02,
+        Self::BG05,
+        Self::BG07,
+        Self::BG09,
+        Self::BG10,
+        Self::BG11,
+        Self::BG13,
+        Self::BG15,
+        Self::BG18,
+        Self::BG23,
+        Self::BG32,
+        Self::BG34,
+        Self::BG45,
+        Self::BG49,
+        Self::BG53,
+        Self::BG57,
+        Self::BG70,
+        Self::BG72,
+        Self::BG75,
+        Self::BG78,
+        Self::BG90,
+        Self::BG93,
+        Self::BG96,
+        Self::BG99,
+        Self::B0000,
+        Self::B000,
+        Self::B00,
+        Self::B01,
+        Self::B02,
+        Self::B04,
+        Self::B05,
+        Self::B06,
+        Self::B12,
+        Self::B14,
+        Self::B16,
+        Self::B18,
+        Self::B21,
+        Self::B23,
+        Self::B24,
+        Self::B26,
+        Self::B28,
+        Self::B29,
+        Self::B32,
+        Self::B34,
+        Self::B37,
+        Self::B39,
+        Self::B41,
+        Self::B45,
+        Self::B52,
+        Self::B60,
+        Self::B63,
+        Self::B66,
+        Self::B69,
+        Self::B79,
+        Self::B91,
+        Self::B93,
+        Self::B95,
+        Self::B97,
+        Self::B99,
+        Self::E0000,
+        Self::E000,
+        Self::E00,
+        Self::E01,
+        Self::E02,
+        Self::E04,
+        Self::E07,
+        Self::E08,
+        Self::E09,
+        Self::E11,
+        Self::E13,
+        Self::E15,
+        Self::E17,
+        Self::E18,
+        Self::E19,
+        Self::E21,
+        Self::E23,
+        Self::E25,
+        Self::E27,
+        Self::E29,
+        Self::E30,
+        Self::E31,
+        Self::E33,
+        Self::E34,
+        Self::E35,
+        Self::E37,
+        Self::E39,
+        Self::E40,
+        Self::E41,
+        Self::E42,
+        Self::E43,
+        Self::E44,
+        Self::E47,
+        Self::E49,
+        Self::E50,
+        Self::E51,
+        Self::E53,
+        Self::E55,
+        Self::E57,
+        Self::E59,
+        Self::E70,
+        Self::E71,
+        Self::E74,
+        Self::E77,
+        Self::E79,
+        Self::E81,
+        Self::E84,
+        Self::E87,
+        Self::E89,
+        Self::E93,
+        Self::E95,
+        Self::E97,
+        Self::E99,
+        Self::C_00,
+        Self::C_0,
+        Self::C_1,
+        Self::C_2,
+        Self::C_3,
+        Self::C_4,
+        Self::C_5,
+        Self::C_6,
+        Self::C_7,
+        Self::C_8,
+        Self::C_9,
+        Self::C_10,
+        Self::N_0,
+        Self::N_1,
+        Self::N_2,
+        Self::N_3,
+        Self::N_4,
+        Self::N_5,
+        Self::N_6,
+        Self::N_7,
+        Self::N_8,
+        Self::N_9,
+        Self::N_10,
+        Self::T_0,
+        Self::T_1,
+        Self::T_2,
+        Self::T_3,
+        Self::T_4,
+        Self::T_5,
+        Self::T_6,
+        Self::T_7,
+        Self::T_8,
+        Self::T_9,
+        Self::T_10,
+        Self::W_00,
+        Self::W_0,
+        Self::W_1,
+        Self::W_2,
+        Self::W_3,
+        Self::W_4,
+        Self::W_5,
+        Self::W_6,
+        Self::W_7,
+        Self::W_8,
+        Self::W_9,
+        Self::W_10,
+        Self::Blender,
+        Self::Black,
+        Self::SpecialBlack,
+        Self::FV,
+        Self::FRV,
+        Self::FYR,
+        Self::FY,
+        Self::FYG,
+        Self::FG,
+        Self::FBG,
+        Self::FB,
+    ];
+
+    /// Returns the numeric value of this ID.
+    pub const fn as_u16(self) -> u16 {
+        self as u16
+    }
+
+    /// Makes an ID from its numeric value.
+    pub const fn from_u16(id: u16) -> Option<Self> {
+        if (id as usize) < ID_COUNT {
+            Some(Self::ALL[CATALOGUE_INDICES[id as usize] as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the color identified by this ID.
+    pub const fn color(self) -> &'static Color {
+        &COLORS[CATALOGUE_INDICES[self as usize] as usize]
+    }
+}
+
+impl TryFrom<u16> for ColorId {
+    type Error = UnknownColorIdError;
+
+    fn try_from(id: u16) -> Result<Self, Self::Error> {
+        Self::from_u16(id).ok_or(UnknownColorIdError(id))
+    }
+}
+
+impl From<ColorId> for u16 {
+    fn from(id: ColorId) -> Self {
+        id.as_u16()
+    }
+}
+
+impl From<ColorId> for Color {
+    fn from(id: ColorId) -> Self {
+        *id.color()
+    }
+}
+
+impl From<ColorId> for &'static Color {
+    fn from(id: ColorId) -> Self {
+        id.color()
+    }
+}
+
+impl Color {
+    /// Returns the stable ID of this color.
+    ///
+    /// Returns `None` if this color is not in [`ALL_COLORS`].
+    pub fn id(&self) -> Option<ColorId> {
+        crate::lookup::index_of(self.code)
+            .filter(|&i| COLORS[i] == *self)
+            .map(|i| ColorId::ALL[i])
+    }
+}
+
+/// Error returned when converting an unassigned number into a [`ColorId`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnknownColorIdError(pub u16);
+
+impl core::fmt::Display for UnknownColorIdError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "unknown color ID: {}", self.0)
+    }
+}
+
+impl core::error::Error for UnknownColorIdError {}
+
+/// Positions in [`ALL_COLORS`] indexed by ID, built at compile time.
+const CATALOGUE_INDICES: [u16; ID_COUNT] = catalogue_indices();
+
+const fn catalogue_indices() -> [u16; ID_COUNT] {
+    assert!(ID_COUNT == ALL_COLORS.len());
+
+    let mut indices = [u16::MAX; ID_COUNT];
+    let mut i = 0;
+    while i < ID_COUNT {
+        let id = ColorId::ALL[i] as usize;
+        assert!(
+            id < ID_COUNT && indices[id] == u16::MAX,
+            "IDs must be unique and contiguous"
+        );
+        indices[id] = i as u16;
+        i += 1;
+    }
+    indices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ALL_COLORS;
+
+    /// `(ID, code)` of every color. IDs must never change, so this table must only be appended to.
+    const IDS: [(u16, &str); 358] = [
+        (0, "BV0000"),
+        (1, "BV000"),
+        (2, "BV00"),
+        (3, "BV01"),
+        (4, "BV02"),
+        (5, "BV04"),
+        (6, "BV08"),
+        (7, "BV11"),
+        (8, "BV13"),
+        (9, "BV17"),
+        (10, "BV20"),
+        (11, "BV23"),
+        (12, "BV25"),
+        (13, "BV29"),
+        (14, "BV31"),
+        (15, "BV34"),
+        (16, "V0000"),
+        (17, "V000"),
+        (18, "V01"),
+        (19, "V04"),
+        (20, "V05"),
+        (21, "V06"),
+        (22, "V09"),
+        (23, "V12"),
+        (24, "V15"),
+        (25, "V17"),
+        (26, "V20"),
+        (27, "V22"),
+        (28, "V25"),
+        (29, "V28"),
+        (30, "V91"),
+        (31, "V93"),
+        (32, "V95"),
+        (33, "V99"),
+        (34, "RV0000"),
+        (35, "RV000"),
+        (36, "RV00"),
+        (37, "RV02"),
+        (38, "RV04"),
+        (39, "RV06"),
+        (40, "RV09"),
+        (41, "RV10"),
+        (42, "RV11"),
+        (43, "RV13"),
+        (44, "RV14"),
+        (45, "RV17"),
+        (46, "RV19"),
+        (47, "RV21"),
+        (48, "RV23"),
+        (49, "RV25"),
+        (50, "RV29"),
+        (51, "RV32"),
+        (52, "RV34"),
+        (53, "RV42"),
+        (54, "RV52"),
+        (55, "RV55"),
+        (56, "RV63"),
+        (57, "RV66"),
+        (58, "RV69"),
+        (59, "RV91"),
+        (60, "RV93"),
+        (61, "RV95"),
+        (62, "RV99"),
+        (63, "R0000"),
+        (64, "R000"),
+        (65, "R00"),
+        (66, "R01"),
+        (67, "R02"),
+        (68, "R05"),
+        (69, "R08"),
+        (70, "R11"),
+        (71, "R12"),
+        (72, "R14"),
+        (73, "R17"),
+        (74, "R20"),
+        (75, "R21"),
+        (76, "R22"),
+        (77, "R24"),
+        (78, "R27"),
+        (79, "R29"),
+        (80, "R30"),
+        (81, "R32"),
+        (82, "R35"),
+        (83, "R37"),
+        (84, "R39"),
+        (85, "R43"),
+        (86, "R46"),
+        (87, "R56"),
+        (88, "R59"),
+        (89, "R81"),
+        (90, "R83"),
+        (91, "R85"),
+        (92, "R89"),
+        (93, "YR0000"),
+        (94, "YR000"),
+        (95, "YR00"),
+        (96, "YR01"),
+        (97, "YR02"),
+        (98, "YR04"),
+        (99, "YR07"),
+        (100, "YR09"),
+        (101, "YR12"),
+        (102, "YR14"),
+        (103, "YR15"),
+        (104, "YR16"),
+        (105, "YR18"),
+        (106, "YR20"),
+        (107, "YR21"),
+        (108, "YR23"),
+        (109, "YR24"),
+        (110, "YR27"),
+        (111, "YR30"),
+        (112, "YR31"),
+        (113, "YR61"),
+        (114, "YR65"),
+        (115, "YR68"),
+        (116, "YR82"),
+        (117, "Y0000"),
+        (118, "Y000"),
+        (119, "Y00"),
+        (120, "Y02"),
+        (121, "Y04"),
+        (122, "Y06"),
+        (123, "Y08"),
+        (124, "Y11"),
+        (125, "Y13"),
+        (126, "Y15"),
+        (127, "Y17"),
+        (128, "Y18"),
+        (129, "Y19"),
+        (130, "Y21"),
+        (131, "Y23"),
+        (132, "Y26"),
+        (133, "Y28"),
+        (134, "Y32"),
+        (135, "Y35"),
+        (136, "Y38"),
+        (137, "YG0000"),
+        (138, "YG00"),
+        (139, "YG01"),
+        (140, "YG03"),
+        (141, "YG05"),
+        (142, "YG06"),
+        (143, "YG07"),
+        (144, "YG09"),
+        (145, "YG11"),
+        (146, "YG13"),
+        (147, "YG17"),
+        (148, "YG21"),
+        (149, "YG23"),
+        (150, "YG25"),
+        (151, "YG41"),
+        (152, "YG45"),
+        (153, "YG61"),
+        (154, "YG63"),
+        (155, "YG67"),
+        (156, "YG91"),
+        (157, "YG93"),
+        (158, "YG95"),
+        (159, "YG97"),
+        (160, "YG99"),
+        (161, "G0000"),
+        (162, "G000"),
+        (163, "G00"),
+        (164, "G02"),
+        (165, "G03"),
+        (166, "G05"),
+        (167, "G07"),
+        (168, "G09"),
+        (169, "G12"),
+        (170, "G14"),
+        (171, "G16"),
+        (172, "G17"),
+        (173, "G19"),
+        (174, "G20"),
+        (175, "G21"),
+        (176, "G24"),
+        (177, "G28"),
+        (178, "G29"),
+        (179, "G40"),
+        (180, "G43"),
+        (181, "G46"),
+        (182, "G82"),
+        (183, "G85"),
+        (184, "G94"),
+        (185, "G99"),
+        (186, "BG0000"),
+        (187, "BG000"),
+        (188, "BG01"),
+        (189, "BG02"),
+        (190, "BG05"),
+        (191, "BG07"),
+        (192, "BG09"),
+        (193, "BG10"),
+        (194, "BG11"),
+        (195, "BG13"),
+        (196, "BG15"),
+        (197, "BG18"),
+        (198, "BG23"),
+        (199, "BG32"),
+        (200, "BG34"),
+        (201, "BG45"),
+        (202, "BG49"),
+        (203, "BG53"),
+        (204, "BG57"),
+        (205, "BG70"),
+        (206, "BG72"),
+        (207, "BG75"),
+        (208, "BG78"),
+        (209, "BG90"),
+        (210, "BG93"),
+        (211, "BG96"),
+        (212, "BG99"),
+        (213, "B0000"),
+        (214, "B000"),
+        (215, "B00"),
+        (216, "B01"),
+        (217, "B02"),
+        (218, "B04"),
+        (219, "B05"),
+        (220, "B06"),
+        (221, "B12"),
+        (222, "B14"),
+        (223, "B16"),
+        (224, "B18"),
+        (225, "B21"),
+        (226, "B23"),
+        (227, "B24"),
+        (228, "B26"),
+        (229, "B28"),
+        (230, "B29"),
+        (231, "B32"),
+        (232, "B34"),
+        (233, "B37"),
+        (234, "B39"),
+        (235, "B41"),
+        (236, "B45"),
+        (237, "B52"),
+        (238, "B60"),
+        (239, "B63"),
+        (240, "B66"),
+        (241, "B69"),
+        (242, "B79"),
+        (243, "B91"),
+        (244, "B93"),
+        (245, "B95"),
+        (246, "B97"),
+        (247, "B99"),
+        (248, "E0000"),
+        (249, "E000"),
+        (250, "E00"),
+        (251, "E01"),
+        (252, "E02"),
+        (253, "E04"),
+        (254, "E07"),
+        (255, "E08"),
+        (256, "E09"),
+        (257, "E11"),
+        (258, "E13"),
+        (259, "E15"),
+        (260, "E17"),
+        (261, "E18"),
+        (262, "E19"),
+        (263, "E21"),
+        (264, "E23"),
+        (265, "E25"),
+        (266, "E27"),
+        (267, "E29"),
+        (268, "E30"),
+        (269, "E31"),
+        (270, "E33"),
+        (271, "E34"),
+        (272, "E35"),
+        (273, "E37"),
+        (274, "E39"),
+        (275, "E40"),
+        (276, "E41"),
+        (277, "E42"),
+        (278, "E43"),
+        (279, "E44"),
+        (280, "E47"),
+        (281, "E49"),
+        (282, "E50"),
+        (283, "E51"),
+        (284, "E53"),
+        (285, "E55"),
+        (286, "E57"),
+        (287, "E59"),
+        (288, "E70"),
+        (289, "E71"),
+        (290, "E74"),
+        (291, "E77"),
+        (292, "E79"),
+        (293, "E81"),
+        (294, "E84"),
+        (295, "E87"),
+        (296, "E89"),
+        (297, "E93"),
+        (298, "E95"),
+        (299, "E97"),
+        (300, "E99"),
+        (301, "C-00"),
+        (302, "C-0"),
+        (303, "C-1"),
+        (304, "C-2"),
+        (305, "C-3"),
+        (306, "C-4"),
+        (307, "C-5"),
+        (308, "C-6"),
+        (309, "C-7"),
+        (310, "C-8"),
+        (311, "C-9"),
+        (312, "C-10"),
+        (313, "N-0"),
+        (314, "N-1"),
+        (315, "N-2"),
+        (316, "N-3"),
+        (317, "N-4"),
+        (318, "N-5"),
+        (319, "N-6"),
+        (320, "N-7"),
+        (321, "N-8"),
+        (322, "N-9"),
+        (323, "N-10"),
+        (324, "T-0"),
+        (325, "T-1"),
+        (326, "T-2"),
+        (327, "T-3"),
+        (328, "T-4"),
+        (329, "T-5"),
+        (330, "T-6"),
+        (331, "T-7"),
+        (332, "T-8"),
+        (333, "T-9"),
+        (334, "T-10"),
+        (335, "W-00"),
+        (336, "W-0"),
+        (337, "W-1"),
+        (338, "W-2"),
+        (339, "W-3"),
+        (340, "W-4"),
+        (341, "W-5"),
+        (342, "W-6"),
+        (343, "W-7"),
+        (344, "W-8"),
+        (345, "W-9"),
+        (346, "W-10"),
+        (347, "0"),
+        (348, "100"),
+        (349, "110"),
+        (350, "FV"),
+        (351, "FRV"),
+        (352, "FYR"),
+        (353, "FY"),
+        (354, "FYG"),
+        (355, "FG"),
+        (356, "FBG"),
+        (357, "FB"),
+    ];
+
+    #[test]
+    fn ids_are_stable() {
+        assert_eq!(ColorId::ALL.len(), IDS.len());
+        for (id, (n, code)) in ColorId::ALL.into_iter().zip(IDS) {
+            assert_eq!(id.as_u16(), n);
+            assert_eq!(id.color().code, code);
+            assert_eq!(ColorId::from_u16(n), Some(id));
+        }
+        assert_eq!(ColorId::BV04 as u16, 5);
+        assert_eq!(ColorId::FB as u16, 357);
+        assert_eq!(ColorId::Blender.color().code, "0");
+    }
+
+    #[test]
+    fn ids_follow_catalogue_order() {
+        for (i, (id, color)) in ColorId::ALL.into_iter().zip(&ALL_COLORS).enumerate() {
+            assert_eq!(usize::from(id.as_u16()), i);
+            assert_eq!(id.color(), color);
+            assert_eq!(color.id(), Some(id));
+        }
+        assert_eq!(ColorId::from_u16(IDS.len() as u16), None);
+        assert_eq!(
+            ColorId::try_from(IDS.len() as u16),
+            Err(UnknownColorIdError(IDS.len() as u16))
+        );
+    }
+}
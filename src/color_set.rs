@@ -0,0 +1,520 @@
+use crate::{Color, ColorId};
+use core::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Sub, SubAssign,
+};
+
+const WORDS: usize = 6;
+const ID_COUNT: usize = ColorId::ALL.len();
+const _: () = assert!(ID_COUNT <= WORDS * 64);
+
+/// Number of bytes of [`ColorSet::to_bytes()`].
+pub const COLOR_SET_BYTES: usize = ID_COUNT.div_ceil(8);
+
+/// Set of colors (e.g., a marker inventory) backed by a fixed-size bitset.
+///
+/// Each bit corresponds to a [`ColorId`], so the binary ([`ColorSet::to_bytes()`]) and text
+/// ([`Display`](core::fmt::Display) / [`FromStr`](core::str::FromStr)) encodings remain valid across releases.
+/// The text encoding is the lowercase hex representation of the bytes without trailing zero bytes
+/// (e.g., an empty set is encoded as an empty string).
+///
+/// Iteration yields colors in catalogue order (the order of [`ALL_COLORS`](crate::ALL_COLORS)).
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct ColorSet {
+    bits: [u64; WORDS],
+}
+
+impl ColorSet {
+    /// The empty set.
+    pub const EMPTY: Self = Self { bits: [0; WORDS] };
+
+    /// The set of all colors in [`ALL_COLORS`](crate::ALL_COLORS).
+    pub const ALL: Self = Self::EMPTY.complement();
+
+    /// Makes an empty set.
+    pub const fn new() -> Self {
+        Self::EMPTY
+    }
+
+    /// Returns a copy of this set with `id` added.
+    ///
+    /// This is a `const` counterpart of [`ColorSet::insert_id()`] that is handy for building static sets.
+    pub const fn with(mut self, id: ColorId) -> Self {
+        let (word, bit) = position(id);
+        self.bits[word] |= bit;
+        self
+    }
+
+    /// Adds `color` to this set, returning `true` if it was not present.
+    ///
+    /// Colors not in [`ALL_COLORS`](crate::ALL_COLORS) are ignored (and `false` is returned).
+    pub fn insert(&mut self, color: &Color) -> bool {
+        color.id().is_some_and(|id| self.insert_id(id))
+    }
+
+    /// Removes `color` from this set, returning `true` if it was present.
+    pub fn remove(&mut self, color: &Color) -> bool {
+        color.id().is_some_and(|id| self.remove_id(id))
+    }
+
+    /// Returns `true` if this set contains `color`.
+    pub fn contains(&self, color: &Color) -> bool {
+        color.id().is_some_and(|id| self.contains_id(id))
+    }
+
+    /// Adds `id` to this set, returning `true` if it was not present.
+    pub fn insert_id(&mut self, id: ColorId) -> bool {
+        let inserted = !self.contains_id(id);
+        *self = self.with(id);
+        inserted
+    }
+
+    /// Removes `id` from this set, returning `true` if it was present.
+    pub fn remove_id(&mut self, id: ColorId) -> bool {
+        let removed = self.contains_id(id);
+        let (word, bit) = position(id);
+        self.bits[word] &= !bit;
+        removed
+    }
+
+    /// Returns `true` if this set contains `id`.
+    pub const fn contains_id(&self, id: ColorId) -> bool {
+        let (word, bit) = position(id);
+        self.bits[word] & bit != 0
+    }
+
+    /// Returns the number of colors in this set.
+    pub const fn len(&self) -> usize {
+        let mut n = 0;
+        let mut i = 0;
+        while i < WORDS {
+            n += self.bits[i].count_ones() as usize;
+            i += 1;
+        }
+        n
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the colors in either `self` or `other`.
+    pub const fn union(self, other: Self) -> Self {
+        self.zip(other, Op::Or)
+    }
+
+    /// Returns the colors in both `self` and `other`.
+    pub const fn intersection(self, other: Self) -> Self {
+        self.zip(other, Op::And)
+    }
+
+    /// Returns the colors in `self` but not in `other` (e.g., the markers a project needs but one does not own).
+    pub const fn difference(self, other: Self) -> Self {
+        self.zip(other, Op::AndNot)
+    }
+
+    /// Returns the colors in exactly one of `self` and `other`.
+    pub const fn symmetric_difference(self, other: Self) -> Self {
+        self.zip(other, Op::Xor)
+    }
+
+    /// Returns the colors in [`ALL_COLORS`](crate::ALL_COLORS) that are not in this set.
+    pub const fn complement(self) -> Self {
+        let mut bits = [0; WORDS];
+        let mut i = 0;
+        while i < WORDS {
+            let valid = if (i + 1) * 64 <= ID_COUNT {
+                u64::MAX
+            } else if i * 64 < ID_COUNT {
+                (1 << (ID_COUNT - i * 64)) - 1
+            } else {
+                0
+            };
+            bits[i] = !self.bits[i] & valid;
+            i += 1;
+        }
+        Self { bits }
+    }
+
+    /// Returns `true` if every color in this set is also in `other`.
+    pub const fn is_subset(&self, other: &Self) -> bool {
+        self.difference(*other).is_empty()
+    }
+
+    /// Returns `true` if every color in `other` is also in this set.
+    pub const fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns `true` if this set and `other` have no colors in common.
+    pub const fn is_disjoint(&self, other: &Self) -> bool {
+        self.intersection(*other).is_empty()
+    }
+
+    /// Returns the colors in this set in catalogue order.
+    pub fn iter(&self) -> ColorSetIter {
+        ColorSetIter {
+            set: *self,
+            ids: ColorId::ALL.iter(),
+        }
+    }
+
+    /// Returns the IDs of the colors in this set in catalogue order.
+    pub fn ids(&self) -> impl '_ + Iterator<Item = ColorId> {
+        ColorId::ALL.into_iter().filter(|&id| self.contains_id(id))
+    }
+
+    /// Encodes this set as bytes (bit `i % 8` of byte `i / 8` is set if the color whose ID is `i` is present).
+    pub const fn to_bytes(&self) -> [u8; COLOR_SET_BYTES] {
+        let mut bytes = [0; COLOR_SET_BYTES];
+        let mut i = 0;
+        while i < COLOR_SET_BYTES {
+            bytes[i] = (self.bits[i / 8] >> ((i % 8) * 8)) as u8;
+            i += 1;
+        }
+        bytes
+    }
+
+    /// Decodes a set encoded by [`ColorSet::to_bytes()`].
+    ///
+    /// Shorter inputs are accepted (the missing bytes are regarded as zero).
+    /// Returns an error if a bit not corresponding to any known [`ColorId`] is set.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, DecodeColorSetError> {
+        let mut set = Self::EMPTY;
+        for (i, &byte) in bytes.iter().enumerate() {
+            for bit in 0..8 {
+                if byte & (1 << bit) == 0 {
+                    continue;
+                }
+                let id = i * 8 + bit;
+                let id = u16::try_from(id)
+                    .ok()
+                    .and_then(ColorId::from_u16)
+                    .ok_or(DecodeColorSetError::UnknownColorId(id))?;
+                set = set.with(id);
+            }
+        }
+        Ok(set)
+    }
+
+    const fn zip(self, other: Self, op: Op) -> Self {
+        let mut bits = [0; WORDS];
+        let mut i = 0;
+        while i < WORDS {
+            let (a, b) = (self.bits[i], other.bits[i]);
+            bits[i] = match op {
+                Op::Or => a | b,
+                Op::And => a & b,
+                Op::AndNot => a & !b,
+                Op::Xor => a ^ b,
+            };
+            i += 1;
+        }
+        Self { bits }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Op {
+    Or,
+    And,
+    AndNot,
+    Xor,
+}
+
+const fn position(id: ColorId) -> (usize, u64) {
+    let i = id as usize;
+    (i / 64, 1 << (i % 64))
+}
+
+impl core::fmt::Debug for ColorSet {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_set().entries(self.iter().map(|c| c.code)).finish()
+    }
+}
+
+impl core::fmt::Display for ColorSet {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let bytes = self.to_bytes();
+        let len = bytes.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+        for b in &bytes[..len] {
+            write!(f, "{b:02x}")?;
+        }
+        Ok(())
+    }
+}
+
+impl core::str::FromStr for ColorSet {
+    type Err = DecodeColorSetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.as_bytes();
+        if s.len() % 2 != 0 {
+            return Err(DecodeColorSetError::InvalidHex);
+        }
+
+        let mut bytes = [0; COLOR_SET_BYTES];
+        for (i, pair) in s.chunks_exact(2).enumerate() {
+            let hex = |c: u8| {
+                (c as char)
+                    .to_digit(16)
+                    .ok_or(DecodeColorSetError::InvalidHex)
+            };
+            let byte = (hex(pair[0])? << 4 | hex(pair[1])?) as u8;
+            match bytes.get_mut(i) {
+                Some(b) => *b = byte,
+                None if byte == 0 => {}
+                None => {
+                    let id = i * 8 + byte.trailing_zeros() as usize;
+                    return Err(DecodeColorSetError::UnknownColorId(id));
+                }
+            }
+        }
+        Self::from_bytes(&bytes)
+    }
+}
+
+/// Error returned when decoding a [`ColorSet`] fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DecodeColorSetError {
+    /// The text encoding is not a valid hex string.
+    InvalidHex,
+
+    /// The encoding contains an ID not assigned to any color (e.g., one encoded by a newer version of this crate).
+    UnknownColorId(usize),
+}
+
+impl core::fmt::Display for DecodeColorSetError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidHex => write!(f, "invalid hex string"),
+            Self::UnknownColorId(id) => write!(f, "unknown color ID: {id}"),
+        }
+    }
+}
+
+impl core::error::Error for DecodeColorSetError {}
+
+/// Iterator over the colors in a [`ColorSet`], returned by [`ColorSet::iter()`].
+#[derive(Debug, Clone)]
+pub struct ColorSetIter {
+    set: ColorSet,
+    ids: core::slice::Iter<'static, ColorId>,
+}
+
+impl Iterator for ColorSetIter {
+    type Item = &'static Color;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let set = self.set;
+        self.ids
+            .find(|&&id| set.contains_id(id))
+            .map(|id| id.color())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.ids.size_hint().1)
+    }
+}
+
+impl DoubleEndedIterator for ColorSetIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let set = self.set;
+        self.ids
+            .rfind(|&&id| set.contains_id(id))
+            .map(|id| id.color())
+    }
+}
+
+impl core::iter::FusedIterator for ColorSetIter {}
+
+impl IntoIterator for ColorSet {
+    type Item = &'static Color;
+    type IntoIter = ColorSetIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &ColorSet {
+    type Item = &'static Color;
+    type IntoIter = ColorSetIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl FromIterator<Color> for ColorSet {
+    fn from_iter<T: IntoIterator<Item = Color>>(iter: T) -> Self {
+        let mut set = Self::EMPTY;
+        set.extend(iter);
+        set
+    }
+}
+
+impl<'a> FromIterator<&'a Color> for ColorSet {
+    fn from_iter<T: IntoIterator<Item = &'a Color>>(iter: T) -> Self {
+        let mut set = Self::EMPTY;
+        set.extend(iter);
+        set
+    }
+}
+
+impl FromIterator<ColorId> for ColorSet {
+    fn from_iter<T: IntoIterator<Item = ColorId>>(iter: T) -> Self {
+        let mut set = Self::EMPTY;
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Color> for ColorSet {
+    fn extend<T: IntoIterator<Item = Color>>(&mut self, iter: T) {
+        for color in iter {
+            self.insert(&color);
+        }
+    }
+}
+
+impl<'a> Extend<&'a Color> for ColorSet {
+    fn extend<T: IntoIterator<Item = &'a Color>>(&mut self, iter: T) {
+        for color in iter {
+            self.insert(color);
+        }
+    }
+}
+
+impl Extend<ColorId> for ColorSet {
+    fn extend<T: IntoIterator<Item = ColorId>>(&mut self, iter: T) {
+        for id in iter {
+            self.insert_id(id);
+        }
+    }
+}
+
+impl BitOr for ColorSet {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for ColorSet {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = self.union(rhs);
+    }
+}
+
+impl BitAnd for ColorSet {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self {
+        self.intersection(rhs)
+    }
+}
+
+impl BitAndAssign for ColorSet {
+    fn bitand_assign(&mut self, rhs: Self) {
+        *self = self.intersection(rhs);
+    }
+}
+
+impl Sub for ColorSet {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.difference(rhs)
+    }
+}
+
+impl SubAssign for ColorSet {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.difference(rhs);
+    }
+}
+
+impl BitXor for ColorSet {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self {
+        self.symmetric_difference(rhs)
+    }
+}
+
+impl BitXorAssign for ColorSet {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        *self = self.symmetric_difference(rhs);
+    }
+}
+
+impl Not for ColorSet {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        self.complement()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::string::ToString;
+
+    #[test]
+    fn empty_set_encoding() {
+        assert_eq!(ColorSet::EMPTY.to_string(), "");
+        assert_eq!(ColorSet::EMPTY.to_bytes(), [0; COLOR_SET_BYTES]);
+        assert_eq!("".parse(), Ok(ColorSet::EMPTY));
+        assert_eq!(ColorSet::from_bytes(&[]), Ok(ColorSet::EMPTY));
+    }
+
+    #[test]
+    fn all_set_round_trip() {
+        let s = ColorSet::ALL.to_string();
+        assert_eq!(s.len(), COLOR_SET_BYTES * 2);
+        assert_eq!(s.parse(), Ok(ColorSet::ALL));
+        assert_eq!(
+            ColorSet::from_bytes(&ColorSet::ALL.to_bytes()),
+            Ok(ColorSet::ALL)
+        );
+        assert_eq!(ColorSet::ALL.len(), ColorId::ALL.len());
+        assert!(ColorSet::ALL.iter().eq(crate::ALL_COLORS.iter()));
+    }
+
+    #[test]
+    fn trailing_zero_bytes_are_trimmed() {
+        let set = ColorSet::EMPTY.with(ColorId::BV0000).with(ColorId::BV04);
+        assert_eq!(set.to_string(), "21");
+        assert_eq!("21".parse(), Ok(set));
+        assert_eq!("210000".parse(), Ok(set));
+        assert_eq!(ColorSet::from_bytes(&[0x21, 0, 0]), Ok(set));
+
+        let set = ColorSet::EMPTY.with(ColorId::FB);
+        let s = set.to_string();
+        assert_eq!(s.len(), COLOR_SET_BYTES * 2);
+        assert_eq!(s.parse(), Ok(set));
+    }
+
+    #[test]
+    fn invalid_hex() {
+        for s in ["0", "123", "0g", "zz", " 01", "é0"] {
+            assert_eq!(s.parse::<ColorSet>(), Err(DecodeColorSetError::InvalidHex));
+        }
+    }
+
+    #[test]
+    fn unknown_color_ids() {
+        assert_eq!(
+            ColorSet::from_bytes(&[0xff; COLOR_SET_BYTES + 1]),
+            Err(DecodeColorSetError::UnknownColorId(ColorId::ALL.len()))
+        );
+        let mut s = "00".repeat(COLOR_SET_BYTES);
+        s.push_str("02");
+        assert_eq!(
+            s.parse::<ColorSet>(),
+            Err(DecodeColorSetError::UnknownColorId(COLOR_SET_BYTES * 8 + 1))
+        );
+    }
+}
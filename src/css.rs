@@ -0,0 +1,117 @@
+//! CSS, SCSS and Tailwind theme generation.
+//!
+//! Pass [`ALL_COLORS`](crate::ALL_COLORS) (or any subset of it) to generate stylesheets that always match this crate.
+use crate::{Color, Family};
+use core::fmt::{self, Write};
+
+/// Writes a `:root` rule declaring a CSS custom property for each color (e.g., `--copic-bv04: #92a4ce;`).
+pub fn write_custom_properties<W: Write>(mut writer: W, colors: &[Color]) -> fmt::Result {
+    writeln!(writer, ":root {{")?;
+    for color in colors {
+        writeln!(writer, "  --copic-{}: {};", Slug(color.code), color.rgb)?;
+    }
+    writeln!(writer, "}}")
+}
+
+/// Writes a SCSS map keyed by code (e.g., `"BV04": #92a4ce,`).
+pub fn write_scss_map<W: Write>(mut writer: W, colors: &[Color]) -> fmt::Result {
+    writeln!(writer, "$copic-colors: (")?;
+    for color in colors {
+        writeln!(writer, "  \"{}\": {},", color.code, color.rgb)?;
+    }
+    writeln!(writer, ");")
+}
+
+/// Writes a Tailwind-compatible JSON theme with colors grouped by [`Family`].
+///
+/// The output has the form `{"copic": {"blue-violet": {"bv04": "#92a4ce", ...}, ...}}`,
+/// which can be merged into `theme.extend.colors` so that classes such as `bg-copic-blue-violet-bv04` are available.
+pub fn write_tailwind_theme<W: Write>(mut writer: W, colors: &[Color]) -> fmt::Result {
+    write!(writer, "{{\n  \"copic\": {{")?;
+    let families = colors.iter().map(|c| c.family).collect::<FamilyList>();
+    for (i, family) in families.iter().enumerate() {
+        if i > 0 {
+            write!(writer, ",")?;
+        }
+        write!(writer, "\n    \"{}\": {{", family_slug(family))?;
+        for (j, color) in colors.iter().filter(|c| c.family == family).enumerate() {
+            if j > 0 {
+                write!(writer, ",")?;
+            }
+            write!(
+                writer,
+                "\n      \"{}\": \"{}\"",
+                Slug(color.code),
+                color.rgb
+            )?;
+        }
+        write!(writer, "\n    }}")?;
+    }
+    writeln!(writer, "\n  }}\n}}")
+}
+
+/// Lowercases a code and replaces characters that are not valid in CSS identifiers.
+struct Slug<'a>(&'a str);
+
+impl fmt::Display for Slug<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for c in self.0.chars() {
+            if c.is_ascii_alphanumeric() || c == '-' {
+                f.write_char(c.to_ascii_lowercase())?;
+            } else {
+                f.write_char('-')?;
+            }
+        }
+        Ok(())
+    }
+}
+
+const fn family_slug(family: Family) -> &'static str {
+    match family {
+        Family::BlueViolet => "blue-violet",
+        Family::Violet => "violet",
+        Family::RedViolet => "red-violet",
+        Family::Red => "red",
+        Family::YellowRed => "yellow-red",
+        Family::Yellow => "yellow",
+        Family::YellowGreen => "yellow-green",
+        Family::Green => "green",
+        Family::BlueGreen => "blue-green",
+        Family::Blue => "blue",
+        Family::Earth => "earth",
+        Family::CoolGray => "cool-gray",
+        Family::NeutralGray => "neutral-gray",
+        Family::TonerGray => "toner-gray",
+        Family::WarmGray => "warm-gray",
+        Family::Flourescent => "fluorescent",
+        Family::Achromatic => "achromatic",
+    }
+}
+
+/// Families in order of first appearance (without allocation).
+#[derive(Default)]
+struct FamilyList {
+    families: [Option<Family>; 17],
+}
+
+impl FamilyList {
+    fn iter(&self) -> impl '_ + Iterator<Item = Family> {
+        self.families.iter().map_while(|f| *f)
+    }
+}
+
+impl FromIterator<Family> for FamilyList {
+    fn from_iter<T: IntoIterator<Item = Family>>(iter: T) -> Self {
+        let mut list = Self::default();
+        for family in iter {
+            if let Some(slot) = list
+                .families
+                .iter_mut()
+                .find(|f| f.is_none_or(|f| f == family))
+            {
+                *slot = Some(family);
+            }
+        }
+        list
+    }
+}
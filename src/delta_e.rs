@@ -0,0 +1,206 @@
+//! Color difference (ΔE) metrics.
+//!
+//! All functions accept anything convertible into [`Lab`], such as [`Color`](crate::Color) and [`Rgb`](crate::Rgb).
+use crate::math;
+use crate::Lab;
+
+/// Computes the CIE76 color difference (the Euclidean distance in CIELAB).
+pub fn cie76(a: impl Into<Lab>, b: impl Into<Lab>) -> f32 {
+    let (a, b) = (a.into(), b.into());
+    math::sqrtf(square(a.l - b.l) + square(a.a - b.a) + square(a.b - b.b))
+}
+
+/// Computes the CIE94 color difference using the graphic arts weighting factors.
+///
+/// Note that CIE94 is not symmetric: `a` is the reference color and `b` is the sample.
+pub fn cie94(a: impl Into<Lab>, b: impl Into<Lab>) -> f32 {
+    const K1: f64 = 0.045;
+    const K2: f64 = 0.015;
+
+    let (a, b) = (a.into(), b.into());
+    let (l1, a1, b1) = (f64::from(a.l), f64::from(a.a), f64::from(a.b));
+    let (l2, a2, b2) = (f64::from(b.l), f64::from(b.a), f64::from(b.b));
+
+    let c1 = math::hypot(a1, b1);
+    let c2 = math::hypot(a2, b2);
+    let dl = l1 - l2;
+    let dc = c1 - c2;
+    let dh2 = (square64(a1 - a2) + square64(b1 - b2) - square64(dc)).max(0.0);
+
+    let sc = 1.0 + K1 * c1;
+    let sh = 1.0 + K2 * c1;
+    math::sqrt(square64(dl) + square64(dc / sc) + dh2 / square64(sh)) as f32
+}
+
+/// Computes the CIEDE2000 color difference (with `kL = kC = kH = 1`).
+///
+/// The implementation follows "The CIEDE2000 Color-Difference Formula: Implementation Notes,
+/// Supplementary Test Data, and Mathematical Observations" by G. Sharma, W. Wu and E. N. Dalal (2005).
+pub fn ciede2000(a: impl Into<Lab>, b: impl Into<Lab>) -> f32 {
+    let (a, b) = (a.into(), b.into());
+    let (l1, a1, b1) = (f64::from(a.l), f64::from(a.a), f64::from(a.b));
+    let (l2, a2, b2) = (f64::from(b.l), f64::from(b.a), f64::from(b.b));
+
+    let c_bar = (math::hypot(a1, b1) + math::hypot(a2, b2)) / 2.0;
+    let c_bar7 = math::pow(c_bar, 7.0);
+    let g = 0.5 * (1.0 - math::sqrt(c_bar7 / (c_bar7 + math::pow(25.0, 7.0))));
+    let a1 = (1.0 + g) * a1;
+    let a2 = (1.0 + g) * a2;
+    let c1 = math::hypot(a1, b1);
+    let c2 = math::hypot(a2, b2);
+    let h1 = hue_degrees(a1, b1);
+    let h2 = hue_degrees(a2, b2);
+
+    let dl = l2 - l1;
+    let dc = c2 - c1;
+    let dh = if c1 * c2 == 0.0 {
+        0.0
+    } else if (h2 - h1).abs() <= 180.0 {
+        h2 - h1
+    } else if h2 - h1 > 180.0 {
+        h2 - h1 - 360.0
+    } else {
+        h2 - h1 + 360.0
+    };
+    let dh = 2.0 * math::sqrt(c1 * c2) * math::sin((dh / 2.0).to_radians());
+
+    let l_bar = (l1 + l2) / 2.0;
+    let c_bar = (c1 + c2) / 2.0;
+    let h_bar = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+
+    let t = 1.0 - 0.17 * cos_degrees(h_bar - 30.0)
+        + 0.24 * cos_degrees(2.0 * h_bar)
+        + 0.32 * cos_degrees(3.0 * h_bar + 6.0)
+        - 0.20 * cos_degrees(4.0 * h_bar - 63.0);
+    let d_theta = 30.0 * math::exp(-square64((h_bar - 275.0) / 25.0));
+    let c_bar7 = math::pow(c_bar, 7.0);
+    let rc = 2.0 * math::sqrt(c_bar7 / (c_bar7 + math::pow(25.0, 7.0)));
+    let sl = 1.0 + 0.015 * square64(l_bar - 50.0) / math::sqrt(20.0 + square64(l_bar - 50.0));
+    let sc = 1.0 + 0.045 * c_bar;
+    let sh = 1.0 + 0.015 * c_bar * t;
+    let rt = -math::sin((2.0 * d_theta).to_radians()) * rc;
+
+    math::sqrt(
+        square64(dl / sl) + square64(dc / sc) + square64(dh / sh) + rt * (dc / sc) * (dh / sh),
+    ) as f32
+}
+
+fn hue_degrees(a: f64, b: f64) -> f64 {
+    if a == 0.0 && b == 0.0 {
+        return 0.0;
+    }
+    let h = math::atan2(b, a).to_degrees();
+    if h < 0.0 {
+        h + 360.0
+    } else {
+        h
+    }
+}
+
+fn cos_degrees(x: f64) -> f64 {
+    math::cos(x.to_radians())
+}
+
+fn square(x: f32) -> f32 {
+    x * x
+}
+
+fn square64(x: f64) -> f64 {
+    x * x
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ALL_COLORS;
+
+    /// Test data from Sharma et al. (2005), Table 1: `(L1, a1, b1, L2, a2, b2, ΔE00)`.
+    const SHARMA: [(f32, f32, f32, f32, f32, f32, f32); 34] = [
+        (50.0, 2.6772, -79.7751, 50.0, 0.0, -82.7485, 2.0425),
+        (50.0, 3.1571, -77.2803, 50.0, 0.0, -82.7485, 2.8615),
+        (50.0, 2.8361, -74.0200, 50.0, 0.0, -82.7485, 3.4412),
+        (50.0, -1.3802, -84.2814, 50.0, 0.0, -82.7485, 1.0000),
+        (50.0, -1.1848, -84.8006, 50.0, 0.0, -82.7485, 1.0000),
+        (50.0, -0.9009, -85.5211, 50.0, 0.0, -82.7485, 1.0000),
+        (50.0, 0.0, 0.0, 50.0, -1.0, 2.0, 2.3669),
+        (50.0, -1.0, 2.0, 50.0, 0.0, 0.0, 2.3669),
+        (50.0, 2.49, -0.001, 50.0, -2.49, 0.0009, 7.1792),
+        (50.0, 2.49, -0.001, 50.0, -2.49, 0.0010, 7.1792),
+        (50.0, 2.49, -0.001, 50.0, -2.49, 0.0011, 7.2195),
+        (50.0, 2.49, -0.001, 50.0, -2.49, 0.0012, 7.2195),
+        (50.0, -0.001, 2.49, 50.0, 0.0009, -2.49, 4.8045),
+        (50.0, -0.001, 2.49, 50.0, 0.0010, -2.49, 4.8045),
+        (50.0, -0.001, 2.49, 50.0, 0.0011, -2.49, 4.7461),
+        (50.0, 2.5, 0.0, 50.0, 0.0, -2.5, 4.3065),
+        (50.0, 2.5, 0.0, 73.0, 25.0, -18.0, 27.1492),
+        (50.0, 2.5, 0.0, 61.0, -5.0, 29.0, 22.8977),
+        (50.0, 2.5, 0.0, 56.0, -27.0, -3.0, 31.9030),
+        (50.0, 2.5, 0.0, 58.0, 24.0, 15.0, 19.4535),
+        (50.0, 2.5, 0.0, 50.0, 3.1736, 0.5854, 1.0000),
+        (50.0, 2.5, 0.0, 50.0, 3.2972, 0.0, 1.0000),
+        (50.0, 2.5, 0.0, 50.0, 1.8634, 0.5757, 1.0000),
+        (50.0, 2.5, 0.0, 50.0, 3.2592, 0.3350, 1.0000),
+        (
+            60.2574, -34.0099, 36.2677, 60.4626, -34.1751, 39.4387, 1.2644,
+        ),
+        (
+            63.0109, -31.0961, -5.8663, 62.8187, -29.7946, -4.0864, 1.2630,
+        ),
+        (61.2901, 3.7196, -5.3901, 61.4292, 2.2480, -4.9620, 1.8731),
+        (35.0831, -44.1164, 3.7933, 35.0232, -40.0716, 1.5901, 1.8645),
+        (
+            22.7233, 20.0904, -46.6940, 23.0331, 14.9730, -42.5619, 2.0373,
+        ),
+        (36.4612, 47.8580, 18.3852, 36.2715, 50.5065, 21.2231, 1.4146),
+        (90.8027, -2.0831, 1.4410, 91.1528, -1.6435, 0.0447, 1.4441),
+        (90.9257, -0.5406, -0.9208, 88.6381, -0.8985, -0.7239, 1.5381),
+        (6.7747, -0.2908, -2.4247, 5.8714, -0.0985, -2.2286, 0.6377),
+        (2.0776, 0.0795, -1.1350, 0.9033, -0.0636, -0.5514, 0.9082),
+    ];
+
+    #[test]
+    fn ciede2000_matches_sharma_test_data() {
+        for (i, &(l1, a1, b1, l2, a2, b2, expected)) in SHARMA.iter().enumerate() {
+            let (x, y) = (Lab::new(l1, a1, b1), Lab::new(l2, a2, b2));
+            for actual in [ciede2000(x, y), ciede2000(y, x)] {
+                assert!(
+                    (actual - expected).abs() < 1e-4,
+                    "pair {}: {actual} != {expected}",
+                    i + 1
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn zero_distance() {
+        for color in &ALL_COLORS {
+            assert_eq!(cie76(color, color), 0.0);
+            assert_eq!(cie94(color, color), 0.0);
+            assert_eq!(ciede2000(color, color), 0.0);
+        }
+    }
+
+    #[test]
+    fn cie76_is_symmetric() {
+        for pair in ALL_COLORS.windows(2) {
+            assert_eq!(cie76(pair[0], pair[1]), cie76(pair[1], pair[0]));
+        }
+    }
+
+    #[test]
+    fn cie94_is_positive_and_asymmetric() {
+        let (a, b) = (Lab::new(50.0, 2.5, 0.0), Lab::new(73.0, 25.0, -18.0));
+        assert!(cie94(a, b) > 0.0);
+        assert!(cie94(b, a) > 0.0);
+        assert!((cie94(a, b) - cie94(b, a)).abs() > 1e-3);
+    }
+}
@@ -0,0 +1,239 @@
+use crate::{Color, Family, ALL_COLORS};
+
+const COLORS: &[Color] = &ALL_COLORS;
+
+/// Chromatic families in hue-wheel order.
+const WHEEL: [Family; 10] = [
+    Family::BlueViolet,
+    Family::Violet,
+    Family::RedViolet,
+    Family::Red,
+    Family::YellowRed,
+    Family::Yellow,
+    Family::YellowGreen,
+    Family::Green,
+    Family::BlueGreen,
+    Family::Blue,
+];
+
+/// `start..end` ranges of each family in [`ALL_COLORS`], built at compile time.
+static RANGES: [(u16, u16); Family::ALL.len()] = family_ranges();
+
+impl Family {
+    /// All families in declaration order.
+    pub const ALL: [Self; 17] = [
+        Self::BlueViolet,
+        Self::Violet,
+        Self::RedViolet,
+        Self::Red,
+        Self::YellowRed,
+        Self::Yellow,
+        Self::YellowGreen,
+        Self::Green,
+        Self::BlueGreen,
+        Self::Blue,
+        Self::Earth,
+        Self::CoolGray,
+        Self::NeutralGray,
+        Self::TonerGray,
+        Self::WarmGray,
+        Self::Flourescent,
+        Self::Achromatic,
+    ];
+
+    /// Returns the prefix of the codes of this family (e.g., "BV", "YR", "C-", "F").
+    ///
+    /// Returns an empty string for [`Family::Achromatic`], whose codes ("0", "100" and "110") have no prefix.
+    pub const fn prefix(self) -> &'static str {
+        match self {
+            Self::BlueViolet => "BV",
+            Self::Violet => "V",
+            Self::RedViolet => "RV",
+            Self::Red => "R",
+            Self::YellowRed => "YR",
+            Self::Yellow => "Y",
+            Self::YellowGreen => "YG",
+            Self::Green => "G",
+            Self::BlueGreen => "BG",
+            Self::Blue => "B",
+            Self::Earth => "E",
+            Self::CoolGray => "C-",
+            Self::NeutralGray => "N-",
+            Self::TonerGray => "T-",
+            Self::WarmGray => "W-",
+            Self::Flourescent => "F",
+            Self::Achromatic => "",
+        }
+    }
+
+    /// Finds the family having the given code prefix.
+    ///
+    /// The lookup is case-insensitive and the hyphen of gray prefixes is optional (e.g., "c-" and "C" both
+    /// resolve to [`Family::CoolGray`]).
+    pub fn from_prefix(prefix: &str) -> Option<Self> {
+        if prefix.is_empty() {
+            return None;
+        }
+        Self::ALL.into_iter().find(|f| {
+            let p = f.prefix();
+            !p.is_empty()
+                && (p.eq_ignore_ascii_case(prefix)
+                    || p.strip_suffix('-')
+                        .is_some_and(|p| p.eq_ignore_ascii_case(prefix)))
+        })
+    }
+
+    /// Returns the human-readable name of this family (e.g., "Blue Violet", "Fluorescent").
+    pub const fn display_name(self) -> &'static str {
+        match self {
+            Self::BlueViolet => "Blue Violet",
+            Self::Violet => "Violet",
+            Self::RedViolet => "Red Violet",
+            Self::Red => "Red",
+            Self::YellowRed => "Yellow Red",
+            Self::Yellow => "Yellow",
+            Self::YellowGreen => "Yellow Green",
+            Self::Green => "Green",
+            Self::BlueGreen => "Blue Green",
+            Self::Blue => "Blue",
+            Self::Earth => "Earth",
+            Self::CoolGray => "Cool Gray",
+            Self::NeutralGray => "Neutral Gray",
+            Self::TonerGray => "Toner Gray",
+            Self::WarmGray => "Warm Gray",
+            Self::Flourescent => "Fluorescent",
+            Self::Achromatic => "Achromatic",
+        }
+    }
+
+    /// Returns the colors of this family in [`ALL_COLORS`].
+    ///
+    /// The catalogue is ordered by family, so this is a contiguous sub-slice of [`ALL_COLORS`].
+    pub fn colors(self) -> &'static [Color] {
+        let (start, end) = RANGES[self as usize];
+        &COLORS[usize::from(start)..usize::from(end)]
+    }
+
+    /// Returns `true` if this is a gray family ([`Family::CoolGray`], [`Family::NeutralGray`],
+    /// [`Family::TonerGray`] or [`Family::WarmGray`]).
+    pub const fn is_gray(self) -> bool {
+        matches!(
+            self,
+            Self::CoolGray | Self::NeutralGray | Self::TonerGray | Self::WarmGray
+        )
+    }
+
+    /// Returns the next chromatic family on the hue wheel.
+    ///
+    /// The wheel goes BV → V → RV → R → YR → Y → YG → G → BG → B → BV, i.e., the catalogue order
+    /// of the chromatic families (from blue violet through red, yellow and green back to blue).
+    ///
+    /// Returns `None` for the families that are not on the wheel
+    /// ([`Family::Earth`], the grays, [`Family::Flourescent`] and [`Family::Achromatic`]).
+    pub const fn next_on_wheel(self) -> Option<Self> {
+        match self.wheel_index() {
+            Some(i) => Some(WHEEL[(i + 1) % WHEEL.len()]),
+            None => None,
+        }
+    }
+
+    /// Returns the previous chromatic family on the hue wheel (see [`Family::next_on_wheel()`]).
+    pub const fn prev_on_wheel(self) -> Option<Self> {
+        match self.wheel_index() {
+            Some(i) => Some(WHEEL[(i + WHEEL.len() - 1) % WHEEL.len()]),
+            None => None,
+        }
+    }
+
+    const fn wheel_index(self) -> Option<usize> {
+        let mut i = 0;
+        while i < WHEEL.len() {
+            if WHEEL[i] as u8 == self as u8 {
+                return Some(i);
+            }
+            i += 1;
+        }
+        None
+    }
+}
+
+const fn family_ranges() -> [(u16, u16); Family::ALL.len()] {
+    let mut ranges = [(0, 0); Family::ALL.len()];
+    let mut i = 0;
+    while i < COLORS.len() {
+        let family = COLORS[i].family as usize;
+        let start = i;
+        while i < COLORS.len() && COLORS[i].family as usize == family {
+            i += 1;
+        }
+        assert!(
+            ranges[family].1 == 0,
+            "colors of a family must be contiguous in ALL_COLORS"
+        );
+        ranges[family] = (start as u16, i as u16);
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_prefix() {
+        for family in Family::ALL {
+            if family != Family::Achromatic {
+                assert_eq!(Family::from_prefix(family.prefix()), Some(family));
+            }
+        }
+        assert_eq!(Family::from_prefix("BV"), Some(Family::BlueViolet));
+        assert_eq!(Family::from_prefix("bv"), Some(Family::BlueViolet));
+        assert_eq!(Family::from_prefix("B"), Some(Family::Blue));
+        assert_eq!(Family::from_prefix("C"), Some(Family::CoolGray));
+        assert_eq!(Family::from_prefix("c-"), Some(Family::CoolGray));
+        assert_eq!(Family::from_prefix("w"), Some(Family::WarmGray));
+        assert_eq!(Family::from_prefix("F"), Some(Family::Flourescent));
+        assert_eq!(Family::from_prefix(""), None);
+        assert_eq!(Family::from_prefix("-"), None);
+        assert_eq!(Family::from_prefix("X"), None);
+        assert_eq!(Family::from_prefix("BV0"), None);
+    }
+
+    #[test]
+    fn colors() {
+        let mut total = 0;
+        for family in Family::ALL {
+            let colors = family.colors();
+            assert!(!colors.is_empty(), "{family:?}");
+            assert!(colors.iter().all(|c| c.family == family), "{family:?}");
+            total += colors.len();
+        }
+        assert_eq!(total, ALL_COLORS.len());
+    }
+
+    #[test]
+    fn hue_wheel() {
+        for family in Family::ALL {
+            match family.next_on_wheel() {
+                Some(next) => {
+                    assert_eq!(next.prev_on_wheel(), Some(family));
+                    assert_eq!(
+                        family.prev_on_wheel().unwrap().next_on_wheel(),
+                        Some(family)
+                    );
+                }
+                None => assert_eq!(family.prev_on_wheel(), None),
+            }
+        }
+
+        let mut family = Family::BlueViolet;
+        for _ in 0..WHEEL.len() {
+            family = family.next_on_wheel().unwrap();
+        }
+        assert_eq!(family, Family::BlueViolet);
+        assert_eq!(Family::Blue.next_on_wheel(), Some(Family::BlueViolet));
+        assert_eq!(Family::BlueViolet.prev_on_wheel(), Some(Family::Blue));
+        assert_eq!(Family::Earth.next_on_wheel(), None);
+        assert_eq!(Family::CoolGray.prev_on_wheel(), None);
+    }
+}
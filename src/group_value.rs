@@ -0,0 +1,273 @@
+use crate::{Group, Value};
+use core::cmp::Ordering;
+
+impl Group {
+    /// All defined groups from [`Group::S0`] to [`Group::S9`] ([`Group::Undefined`] is not included).
+    pub const ALL: [Self; 10] = [
+        Self::S0,
+        Self::S1,
+        Self::S2,
+        Self::S3,
+        Self::S4,
+        Self::S5,
+        Self::S6,
+        Self::S7,
+        Self::S8,
+        Self::S9,
+    ];
+
+    /// Returns the digit of this group (e.g., `3` for [`Group::S3`]).
+    ///
+    /// Returns `None` for [`Group::Undefined`].
+    pub const fn as_digit(self) -> Option<u8> {
+        match self {
+            Self::Undefined => None,
+            _ => Some(self as u8 - Self::S0 as u8),
+        }
+    }
+
+    /// Makes a group from its digit (`0..=9`).
+    pub const fn from_digit(digit: u8) -> Option<Self> {
+        if digit < Self::ALL.len() as u8 {
+            Some(Self::ALL[digit as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Compares two groups, returning `None` if either of them is [`Group::Undefined`].
+    ///
+    /// Unlike the derived [`Ord`], which treats [`Group::Undefined`] as the lowest group,
+    /// this does not order undefined groups at all.
+    pub fn checked_cmp(self, other: Self) -> Option<Ordering> {
+        Some(self.as_digit()?.cmp(&other.as_digit()?))
+    }
+}
+
+impl TryFrom<u8> for Group {
+    type Error = InvalidDigitsError;
+
+    fn try_from(digit: u8) -> Result<Self, Self::Error> {
+        Self::from_digit(digit).ok_or(InvalidDigitsError)
+    }
+}
+
+impl core::str::FromStr for Group {
+    type Err = InvalidDigitsError;
+
+    /// Parses a group digit as it appears in a color code (e.g., "3"), optionally prefixed by "S" (e.g., "S3").
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.as_bytes();
+        let s = match s {
+            [b'S' | b's', rest @ ..] => rest,
+            _ => s,
+        };
+        match s {
+            &[d @ b'0'..=b'9'] => Ok(Self::ALL[usize::from(d - b'0')]),
+            _ => Err(InvalidDigitsError),
+        }
+    }
+}
+
+impl Value {
+    /// All defined values from the lightest ([`Value::B000`]) to the darkest ([`Value::B9`])
+    /// ([`Value::Undefined`] is not included).
+    pub const ALL: [Self; 12] = [
+        Self::B000,
+        Self::B00,
+        Self::B0,
+        Self::B1,
+        Self::B2,
+        Self::B3,
+        Self::B4,
+        Self::B5,
+        Self::B6,
+        Self::B7,
+        Self::B8,
+        Self::B9,
+    ];
+
+    /// Returns the single digit of this value (e.g., `3` for [`Value::B3`]).
+    ///
+    /// Returns `None` for [`Value::B000`] and [`Value::B00`], which have multiple digits
+    /// (see [`Value::suffix()`] and [`Value::lightness_index()`]), and for [`Value::Undefined`].
+    pub const fn as_digit(self) -> Option<u8> {
+        match self {
+            Self::Undefined | Self::B000 | Self::B00 => None,
+            _ => Some(self as u8 - Self::B0 as u8),
+        }
+    }
+
+    /// Makes a value from its single digit (`0..=9`).
+    pub const fn from_digit(digit: u8) -> Option<Self> {
+        if digit <= 9 {
+            Some(Self::ALL[digit as usize + 2])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the digits of this value as they appear at the end of a color code (e.g., "000", "00", "0", "3").
+    ///
+    /// Returns `None` for [`Value::Undefined`].
+    pub const fn suffix(self) -> Option<&'static str> {
+        Some(match self {
+            Self::Undefined => return None,
+            Self::B000 => "000",
+            Self::B00 => "00",
+            Self::B0 => "0",
+            Self::B1 => "1",
+            Self::B2 => "2",
+            Self::B3 => "3",
+            Self::B4 => "4",
+            Self::B5 => "5",
+            Self::B6 => "6",
+            Self::B7 => "7",
+            Self::B8 => "8",
+            Self::B9 => "9",
+        })
+    }
+
+    /// Returns the position of this value in the continuous lightness scale
+    /// from `0` ([`Value::B000`], the lightest) to `11` ([`Value::B9`], the darkest).
+    ///
+    /// Returns `None` for [`Value::Undefined`].
+    pub const fn lightness_index(self) -> Option<u8> {
+        match self {
+            Self::Undefined => None,
+            _ => Some(self as u8 - Self::B000 as u8),
+        }
+    }
+
+    /// Makes a value from its [`Value::lightness_index()`].
+    pub const fn from_lightness_index(index: u8) -> Option<Self> {
+        if index < Self::ALL.len() as u8 {
+            Some(Self::ALL[index as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Compares the lightness of two values, returning `None` if either of them is [`Value::Undefined`].
+    ///
+    /// Lighter values are smaller. Unlike the derived [`Ord`], which treats [`Value::Undefined`] as the lightest value,
+    /// this does not order undefined values at all.
+    pub fn checked_cmp(self, other: Self) -> Option<Ordering> {
+        Some(self.lightness_index()?.cmp(&other.lightness_index()?))
+    }
+
+    pub(crate) fn from_suffix_bytes(s: &[u8]) -> Option<Self> {
+        match s {
+            b"000" => Some(Self::B000),
+            b"00" => Some(Self::B00),
+            &[d @ b'0'..=b'9'] => Self::from_digit(d - b'0'),
+            _ => None,
+        }
+    }
+}
+
+impl TryFrom<u8> for Value {
+    type Error = InvalidDigitsError;
+
+    fn try_from(digit: u8) -> Result<Self, Self::Error> {
+        Self::from_digit(digit).ok_or(InvalidDigitsError)
+    }
+}
+
+impl core::str::FromStr for Value {
+    type Err = InvalidDigitsError;
+
+    /// Parses value digits as they appear in a color code (e.g., "000", "00", "0", "3"),
+    /// optionally prefixed by "B" (e.g., "B00").
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.as_bytes();
+        let s = match s {
+            [b'B' | b'b', rest @ ..] => rest,
+            _ => s,
+        };
+        Self::from_suffix_bytes(s).ok_or(InvalidDigitsError)
+    }
+}
+
+/// Error returned when converting a digit or string into a [`Group`] or [`Value`] fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InvalidDigitsError;
+
+impl core::fmt::Display for InvalidDigitsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "invalid group or value digits")
+    }
+}
+
+impl core::error::Error for InvalidDigitsError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn group_conversions() {
+        for (digit, group) in (0..).zip(Group::ALL) {
+            assert_eq!(group.as_digit(), Some(digit));
+            assert_eq!(Group::try_from(digit), Ok(group));
+        }
+        assert_eq!(Group::Undefined.as_digit(), None);
+        assert_eq!(Group::try_from(10), Err(InvalidDigitsError));
+        assert_eq!(Group::try_from(u8::MAX), Err(InvalidDigitsError));
+    }
+
+    #[test]
+    fn parse_group() {
+        assert_eq!("0".parse(), Ok(Group::S0));
+        assert_eq!("S3".parse(), Ok(Group::S3));
+        assert_eq!("s9".parse(), Ok(Group::S9));
+        for s in ["", "S", "10", "S10", "B3", "3S", " 3", "-1"] {
+            assert_eq!(s.parse::<Group>(), Err(InvalidDigitsError), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn value_conversions() {
+        for (index, value) in (0..).zip(Value::ALL) {
+            assert_eq!(value.lightness_index(), Some(index));
+            assert_eq!(Value::from_lightness_index(index), Some(value));
+        }
+        assert_eq!(Value::Undefined.lightness_index(), None);
+        assert_eq!(Value::from_lightness_index(12), None);
+
+        assert_eq!(Value::try_from(0), Ok(Value::B0));
+        assert_eq!(Value::try_from(9), Ok(Value::B9));
+        assert_eq!(Value::try_from(10), Err(InvalidDigitsError));
+        assert_eq!(Value::B000.as_digit(), None);
+        assert_eq!(Value::B00.as_digit(), None);
+        assert_eq!(Value::B7.as_digit(), Some(7));
+    }
+
+    #[test]
+    fn parse_value() {
+        assert_eq!("000".parse(), Ok(Value::B000));
+        assert_eq!("B00".parse(), Ok(Value::B00));
+        assert_eq!("b0".parse(), Ok(Value::B0));
+        assert_eq!("5".parse(), Ok(Value::B5));
+        for value in Value::ALL {
+            assert_eq!(value.suffix().unwrap().parse(), Ok(value));
+        }
+        for s in ["", "B", "0000", "01", "10", "S3", "3B"] {
+            assert_eq!(s.parse::<Value>(), Err(InvalidDigitsError), "{s:?}");
+        }
+    }
+
+    #[test]
+    fn checked_cmp() {
+        assert_eq!(Group::S1.checked_cmp(Group::S2), Some(Ordering::Less));
+        assert_eq!(Group::S2.checked_cmp(Group::S2), Some(Ordering::Equal));
+        assert_eq!(Group::S0.checked_cmp(Group::Undefined), None);
+        assert_eq!(Group::Undefined.checked_cmp(Group::Undefined), None);
+
+        assert_eq!(Value::B000.checked_cmp(Value::B00), Some(Ordering::Less));
+        assert_eq!(Value::B00.checked_cmp(Value::B0), Some(Ordering::Less));
+        assert_eq!(Value::B9.checked_cmp(Value::B1), Some(Ordering::Greater));
+        assert_eq!(Value::B3.checked_cmp(Value::Undefined), None);
+        assert_eq!(Value::Undefined.checked_cmp(Value::B000), None);
+    }
+}
@@ -0,0 +1,651 @@
+//! Image quantization to Copic colors and palette selection.
+use crate::math;
+use crate::{Color, Lab, Rgb};
+use alloc::vec::Vec;
+
+/// Dithering method used by [`quantize()`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dithering {
+    /// Each pixel is mapped to its nearest color.
+    #[default]
+    None,
+
+    /// Floyd–Steinberg error diffusion.
+    FloydSteinberg,
+
+    /// Ordered dithering with an 8x8 Bayer matrix.
+    ///
+    /// The threshold spreads over ±[`BAYER_SPREAD`]/2 per RGB channel.
+    Bayer,
+}
+
+/// Amplitude of the offsets added to the RGB channels by [`Dithering::Bayer`].
+pub const BAYER_SPREAD: f32 = 32.0;
+
+/// Maps each pixel of an image to the nearest color in `palette`.
+///
+/// `rgb` is a row-major buffer of packed 8-bit RGB pixels (i.e., `width * height * 3` bytes).
+/// Colors are matched in the same way as [`nearest()`](crate::nearest()).
+pub fn quantize<'a, I>(
+    rgb: &[u8],
+    width: usize,
+    height: usize,
+    palette: I,
+    dithering: Dithering,
+) -> Result<Quantized<'a>, Error>
+where
+    I: IntoIterator<Item = &'a Color>,
+{
+    let palette = palette.into_iter().collect::<Vec<_>>();
+    if palette.is_empty() {
+        return Err(Error::EmptyPalette);
+    }
+    if palette.len() > usize::from(u16::MAX) + 1 {
+        return Err(Error::TooLargePalette);
+    }
+    if width.checked_mul(height).and_then(|n| n.checked_mul(3)) != Some(rgb.len()) {
+        return Err(Error::InvalidBufferSize);
+    }
+
+    let nearest = |rgb: Rgb| nearest_index(rgb, &palette) as u16;
+    let pixel = |x: usize, y: usize| {
+        let i = (y * width + x) * 3;
+        [rgb[i], rgb[i + 1], rgb[i + 2]].map(f32::from)
+    };
+
+    let mut indices = Vec::with_capacity(width * height);
+    match dithering {
+        Dithering::None => {
+            // Images usually contain many repeated colors, so remember the last match.
+            let mut last = None;
+            for px in rgb.chunks_exact(3) {
+                let px = Rgb::new(px[0], px[1], px[2]);
+                let i = match last {
+                    Some((prev, i)) if prev == px => i,
+                    _ => nearest(px),
+                };
+                last = Some((px, i));
+                indices.push(i);
+            }
+        }
+        Dithering::FloydSteinberg => {
+            // Accumulated errors of the current and the next rows (with one pixel of padding on each side).
+            let mut errors = [
+                alloc::vec![[0.0f32; 3]; width + 2],
+                alloc::vec![[0.0f32; 3]; width + 2],
+            ];
+            for y in 0..height {
+                for x in 0..width {
+                    let mut px = pixel(x, y);
+                    for (c, e) in px.iter_mut().zip(errors[0][x + 1]) {
+                        // Clamping keeps errors from growing unboundedly for colors outside the palette's gamut.
+                        *c = (*c + e).clamp(0.0, 255.0);
+                    }
+                    let i = nearest(to_rgb(px));
+                    indices.push(i);
+
+                    let q = palette[usize::from(i)].rgb;
+                    let q = [q.r, q.g, q.b].map(f32::from);
+                    for c in 0..3 {
+                        let e = px[c] - q[c];
+                        errors[0][x + 2][c] += e * 7.0 / 16.0;
+                        errors[1][x][c] += e * 3.0 / 16.0;
+                        errors[1][x + 1][c] += e * 5.0 / 16.0;
+                        errors[1][x + 2][c] += e * 1.0 / 16.0;
+                    }
+                }
+                errors.swap(0, 1);
+                errors[1].fill([0.0; 3]);
+            }
+        }
+        Dithering::Bayer => {
+            for y in 0..height {
+                for x in 0..width {
+                    let t = (f32::from(BAYER_8X8[y % 8][x % 8]) + 0.5) / 64.0 - 0.5;
+                    let px = pixel(x, y).map(|c| c + t * BAYER_SPREAD);
+                    indices.push(nearest(to_rgb(px)));
+                }
+            }
+        }
+    }
+
+    let mut counts = alloc::vec![0; palette.len()];
+    for &i in &indices {
+        counts[usize::from(i)] += 1;
+    }
+    Ok(Quantized {
+        width,
+        height,
+        palette,
+        indices,
+        counts,
+    })
+}
+
+/// Image quantized by [`quantize()`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Quantized<'a> {
+    width: usize,
+    height: usize,
+    palette: Vec<&'a Color>,
+    indices: Vec<u16>,
+    counts: Vec<usize>,
+}
+
+impl<'a> Quantized<'a> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the palette given to [`quantize()`].
+    pub fn palette(&self) -> &[&'a Color] {
+        &self.palette
+    }
+
+    /// Returns the row-major indices into [`Quantized::palette()`] of the pixels.
+    pub fn indices(&self) -> &[u16] {
+        &self.indices
+    }
+
+    /// Returns the color of the pixel at `(x, y)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `(x, y)` is out of bounds.
+    pub fn color_at(&self, x: usize, y: usize) -> &'a Color {
+        assert!(x < self.width && y < self.height, "out of bounds");
+        self.palette[usize::from(self.indices[y * self.width + x])]
+    }
+
+    /// Returns the colors of the pixels in row-major order.
+    pub fn colors(&self) -> impl '_ + ExactSizeIterator<Item = &'a Color> {
+        self.indices.iter().map(|&i| self.palette[usize::from(i)])
+    }
+
+    /// Returns the remapped image as a packed 8-bit RGB buffer.
+    pub fn to_rgb_bytes(&self) -> Vec<u8> {
+        self.colors()
+            .flat_map(|c| [c.rgb.r, c.rgb.g, c.rgb.b])
+            .collect()
+    }
+
+    /// Returns how many pixels each color covers, from the largest coverage to the smallest.
+    ///
+    /// Colors covering no pixels are omitted.
+    pub fn coverage(&self) -> Vec<Coverage<'a>> {
+        let total = self.indices.len() as f32;
+        let mut coverage = self
+            .palette
+            .iter()
+            .zip(&self.counts)
+            .filter(|(_, &n)| n > 0)
+            .map(|(&color, &pixels)| Coverage {
+                color,
+                pixels,
+                ratio: pixels as f32 / total,
+            })
+            .collect::<Vec<_>>();
+        coverage.sort_by_key(|c| core::cmp::Reverse(c.pixels));
+        coverage
+    }
+}
+
+/// Number of pixels covered by a color, returned by [`Quantized::coverage()`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Coverage<'a> {
+    pub color: &'a Color,
+    pub pixels: usize,
+
+    /// `pixels` divided by the total number of pixels.
+    pub ratio: f32,
+}
+
+/// Error returned by [`quantize()`] and [`select_colors()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Error {
+    /// The length of the RGB buffer is not `width * height * 3`.
+    InvalidBufferSize,
+
+    /// The palette has no colors (or zero colors are requested).
+    EmptyPalette,
+
+    /// The palette has more than 65536 colors.
+    TooLargePalette,
+}
+
+impl core::fmt::Display for Error {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidBufferSize => write!(f, "RGB buffer size does not match the image size"),
+            Self::EmptyPalette => write!(f, "empty palette"),
+            Self::TooLargePalette => write!(f, "too large palette"),
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+/// Maximum number of pixels sampled by [`select_colors()`].
+pub const MAX_SAMPLES: usize = 65536;
+
+/// Chooses the `n` colors in `candidates` that best reproduce an image.
+///
+/// This runs k-means clustering (with k = `n`) over the pixels in CIELAB and then snaps each cluster center
+/// to a distinct nearest candidate, so `candidates` can be [`ALL_COLORS`](crate::ALL_COLORS)
+/// or only the markers one owns.
+/// Centers are snapped, and pixels are assigned to the chosen colors, in the same way as [`quantize()`] matches colors,
+/// so [`Selection::palette()`] can be passed to [`quantize()`] as is.
+/// If the image has more than [`MAX_SAMPLES`] pixels, an evenly spaced subset of them is used.
+/// The result is deterministic.
+///
+/// If `n` is larger than the number of distinct candidates, all of them are chosen.
+/// If the image has no pixels, no color is chosen.
+/// `rgb`, `width` and `height` are the same as [`quantize()`].
+pub fn select_colors<'a, I>(
+    rgb: &[u8],
+    width: usize,
+    height: usize,
+    candidates: I,
+    n: usize,
+) -> Result<Selection<'a>, Error>
+where
+    I: IntoIterator<Item = &'a Color>,
+{
+    let mut unique = Vec::<&Color>::new();
+    for color in candidates {
+        if !unique.iter().any(|c| c.code == color.code) {
+            unique.push(color);
+        }
+    }
+    let candidates = unique;
+    if candidates.is_empty() || n == 0 {
+        return Err(Error::EmptyPalette);
+    }
+    if width.checked_mul(height).and_then(|n| n.checked_mul(3)) != Some(rgb.len()) {
+        return Err(Error::InvalidBufferSize);
+    }
+
+    let pixels = width * height;
+    let step = pixels.div_ceil(MAX_SAMPLES).max(1);
+    let samples = rgb
+        .chunks_exact(3)
+        .step_by(step)
+        .map(|px| Rgb::new(px[0], px[1], px[2]))
+        .collect::<Vec<_>>();
+    let sample_labs = samples.iter().map(|s| s.to_lab()).collect::<Vec<_>>();
+
+    let chosen = if samples.is_empty() {
+        Vec::new()
+    } else if n >= candidates.len() {
+        (0..candidates.len()).collect()
+    } else {
+        let centers = k_means(&sample_labs, n);
+
+        // Snap the centers to distinct candidates, starting from the most populated cluster.
+        let mut sizes = alloc::vec![0usize; centers.len()];
+        for s in &sample_labs {
+            sizes[nearest_lab(*s, &centers)] += 1;
+        }
+        let mut order = (0..centers.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&i| core::cmp::Reverse(sizes[i]));
+
+        let mut chosen = Vec::<usize>::with_capacity(n);
+        for i in order {
+            let center = centers[i].to_rgb();
+            let best = (0..candidates.len())
+                .filter(|j| !chosen.contains(j))
+                .min_by_key(|&j| center.redmean_distance(candidates[j].rgb))
+                .unwrap_or_else(|| unreachable!());
+            chosen.push(best);
+        }
+        chosen
+    };
+
+    // Error report.
+    let chosen_colors = chosen.iter().map(|&i| candidates[i]).collect::<Vec<_>>();
+    let mut counts = alloc::vec![0usize; chosen.len()];
+    let mut errors = alloc::vec![0.0f64; chosen.len()];
+    let mut max_delta_e = 0.0f32;
+    for (s, lab) in samples.iter().zip(&sample_labs) {
+        let i = nearest_index(*s, &chosen_colors);
+        let delta_e = math::sqrtf(lab_distance2(*lab, chosen_colors[i].lab()));
+        counts[i] += 1;
+        errors[i] += f64::from(delta_e);
+        max_delta_e = max_delta_e.max(delta_e);
+    }
+    let total = samples.len().max(1) as f64;
+    let mut colors = chosen_colors
+        .iter()
+        .enumerate()
+        .map(|(i, &color)| SelectedColor {
+            color,
+            ratio: (counts[i] as f64 / total) as f32,
+            mean_delta_e: if counts[i] == 0 {
+                0.0
+            } else {
+                (errors[i] / counts[i] as f64) as f32
+            },
+        })
+        .collect::<Vec<_>>();
+    colors.sort_by(|a, b| b.ratio.total_cmp(&a.ratio));
+    Ok(Selection {
+        colors,
+        mean_delta_e: (errors.iter().sum::<f64>() / total) as f32,
+        max_delta_e,
+    })
+}
+
+/// Result of [`select_colors()`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Selection<'a> {
+    /// Chosen colors, from the largest coverage to the smallest.
+    pub colors: Vec<SelectedColor<'a>>,
+
+    /// Mean CIE76 color difference between each (sampled) pixel and the chosen color it is mapped to.
+    pub mean_delta_e: f32,
+
+    /// Maximum CIE76 color difference between a (sampled) pixel and the chosen color it is mapped to.
+    pub max_delta_e: f32,
+}
+
+impl<'a> Selection<'a> {
+    /// Returns the chosen colors, e.g., to pass them to [`quantize()`].
+    pub fn palette(&self) -> impl '_ + ExactSizeIterator<Item = &'a Color> {
+        self.colors.iter().map(|c| c.color)
+    }
+}
+
+/// Color chosen by [`select_colors()`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SelectedColor<'a> {
+    pub color: &'a Color,
+
+    /// Fraction of the (sampled) pixels mapped to this color (as [`quantize()`] would do).
+    pub ratio: f32,
+
+    /// Mean CIE76 color difference between this color and the pixels it covers.
+    pub mean_delta_e: f32,
+}
+
+/// Lloyd's k-means with k-means++ seeding (using a fixed seed).
+fn k_means(samples: &[Lab], k: usize) -> Vec<Lab> {
+    const MAX_ITERATIONS: usize = 30;
+
+    let mut rng = XorShift64(0x9E37_79B9_7F4A_7C15);
+    let mut centers = Vec::with_capacity(k);
+    centers.push(samples[rng.next_index(samples.len())]);
+    let mut distances = samples
+        .iter()
+        .map(|s| lab_distance2(*s, centers[0]))
+        .collect::<Vec<_>>();
+    while centers.len() < k {
+        let total = distances.iter().map(|&d| f64::from(d)).sum::<f64>();
+        let next = if total == 0.0 {
+            // There are fewer distinct pixels than k.
+            samples[rng.next_index(samples.len())]
+        } else {
+            let mut target = rng.next_f64() * total;
+            let i = distances
+                .iter()
+                .position(|&d| {
+                    target -= f64::from(d);
+                    target <= 0.0
+                })
+                .unwrap_or(samples.len() - 1);
+            samples[i]
+        };
+        for (d, s) in distances.iter_mut().zip(samples) {
+            *d = d.min(lab_distance2(*s, next));
+        }
+        centers.push(next);
+    }
+
+    let mut assignments = alloc::vec![usize::MAX; samples.len()];
+    for _ in 0..MAX_ITERATIONS {
+        let mut changed = false;
+        for (a, s) in assignments.iter_mut().zip(samples) {
+            let i = nearest_lab(*s, &centers);
+            changed |= *a != i;
+            *a = i;
+        }
+        if !changed {
+            break;
+        }
+
+        let mut sums = alloc::vec![([0.0f64; 3], 0usize); k];
+        for (&a, s) in assignments.iter().zip(samples) {
+            let (sum, n) = &mut sums[a];
+            sum[0] += f64::from(s.l);
+            sum[1] += f64::from(s.a);
+            sum[2] += f64::from(s.b);
+            *n += 1;
+        }
+        for (center, (sum, n)) in centers.iter_mut().zip(sums) {
+            if n > 0 {
+                let n = n as f64;
+                *center = Lab::new(
+                    (sum[0] / n) as f32,
+                    (sum[1] / n) as f32,
+                    (sum[2] / n) as f32,
+                );
+            }
+        }
+    }
+    centers
+}
+
+/// Returns the index of the color in `palette` nearest to `rgb` (in the same way as [`nearest()`](crate::nearest())).
+fn nearest_index(rgb: Rgb, palette: &[&Color]) -> usize {
+    palette
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, c)| rgb.redmean_distance(c.rgb))
+        .map(|(i, _)| i)
+        .unwrap_or_else(|| unreachable!())
+}
+
+fn nearest_lab(lab: Lab, candidates: &[Lab]) -> usize {
+    candidates
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| lab_distance2(lab, **a).total_cmp(&lab_distance2(lab, **b)))
+        .map(|(i, _)| i)
+        .unwrap_or_else(|| unreachable!())
+}
+
+fn lab_distance2(a: Lab, b: Lab) -> f32 {
+    let (dl, da, db) = (a.l - b.l, a.a - b.a, a.b - b.b);
+    dl * dl + da * da + db * db
+}
+
+/// Small deterministic PRNG for the k-means++ seeding.
+struct XorShift64(u64);
+
+impl XorShift64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    fn next_index(&mut self, len: usize) -> usize {
+        (self.next_u64() % len as u64) as usize
+    }
+}
+
+fn to_rgb(px: [f32; 3]) -> Rgb {
+    let [r, g, b] = px.map(|c| math::roundf(c.clamp(0.0, 255.0)) as u8);
+    Rgb::new(r, g, b)
+}
+
+const BAYER_8X8: [[u8; 8]; 8] = [
+    [0, 32, 8, 40, 2, 34, 10, 42],
+    [48, 16, 56, 24, 50, 18, 58, 26],
+    [12, 44, 4, 36, 14, 46, 6, 38],
+    [60, 28, 52, 20, 62, 30, 54, 22],
+    [3, 35, 11, 43, 1, 33, 9, 41],
+    [51, 19, 59, 27, 49, 17, 57, 25],
+    [15, 47, 7, 39, 13, 45, 5, 37],
+    [63, 31, 55, 23, 61, 29, 53, 21],
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ALL_COLORS;
+
+    /// Left half red, right half blue.
+    fn red_blue(width: usize, height: usize) -> Vec<u8> {
+        (0..width * height)
+            .flat_map(|i| {
+                if i % width < width / 2 {
+                    [255, 0, 0]
+                } else {
+                    [0, 0, 255]
+                }
+            })
+            .collect()
+    }
+
+    /// The darkest and the lightest colors.
+    fn black_white() -> [&'static Color; 2] {
+        let brightness = |c: &&Color| u32::from(c.rgb.r) + u32::from(c.rgb.g) + u32::from(c.rgb.b);
+        [
+            ALL_COLORS.iter().min_by_key(brightness).unwrap(),
+            ALL_COLORS.iter().max_by_key(brightness).unwrap(),
+        ]
+    }
+
+    fn midpoint([a, b]: [&Color; 2]) -> Rgb {
+        Rgb::new(
+            a.rgb.r / 2 + b.rgb.r / 2,
+            a.rgb.g / 2 + b.rgb.g / 2,
+            a.rgb.b / 2 + b.rgb.b / 2,
+        )
+    }
+
+    /// Image of a single color.
+    fn solid(rgb: Rgb, width: usize, height: usize) -> Vec<u8> {
+        [rgb.r, rgb.g, rgb.b].repeat(width * height)
+    }
+
+    fn mean(rgb: &[u8]) -> [f32; 3] {
+        let mut sum = [0.0; 3];
+        for px in rgb.chunks_exact(3) {
+            for (s, &c) in sum.iter_mut().zip(px) {
+                *s += f32::from(c);
+            }
+        }
+        sum.map(|s| s / (rgb.len() / 3) as f32)
+    }
+
+    #[test]
+    fn dithering_keeps_palette_colors() {
+        let palette = black_white();
+        for dithering in [Dithering::None, Dithering::FloydSteinberg, Dithering::Bayer] {
+            for color in palette {
+                let rgb = solid(color.rgb, 16, 16);
+                let quantized = quantize(&rgb, 16, 16, palette, dithering).unwrap();
+                assert_eq!(quantized.to_rgb_bytes(), rgb, "{dithering:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn floyd_steinberg_dithering() {
+        let palette = black_white();
+        let rgb = solid(midpoint(palette), 16, 16);
+
+        let plain = quantize(&rgb, 16, 16, palette, Dithering::None).unwrap();
+        assert_eq!(plain.coverage().len(), 1);
+
+        let dithered = quantize(&rgb, 16, 16, palette, Dithering::FloydSteinberg).unwrap();
+        let coverage = dithered.coverage();
+        assert_eq!(coverage.len(), 2);
+        assert!((coverage[0].ratio - 0.5).abs() < 0.05, "{coverage:?}");
+        for (actual, expected) in mean(&dithered.to_rgb_bytes()).into_iter().zip(mean(&rgb)) {
+            assert!((actual - expected).abs() < 8.0, "{actual} vs {expected}");
+        }
+    }
+
+    #[test]
+    fn bayer_dithering() {
+        let palette = black_white();
+        let rgb = solid(midpoint(palette), 16, 16);
+
+        let dithered = quantize(&rgb, 16, 16, palette, Dithering::Bayer).unwrap();
+        assert_eq!(dithered.coverage().len(), 2);
+
+        // The pattern repeats every 8 pixels in both directions.
+        for y in 0..8 {
+            for x in 0..8 {
+                let color = dithered.color_at(x, y);
+                assert_eq!(dithered.color_at(x + 8, y), color);
+                assert_eq!(dithered.color_at(x, y + 8), color);
+                assert_eq!(dithered.color_at(x + 8, y + 8), color);
+            }
+        }
+    }
+
+    #[test]
+    fn selection_is_consistent_with_quantize() {
+        let rgb = red_blue(8, 4);
+        let selection = select_colors(&rgb, 8, 4, &ALL_COLORS, 2).unwrap();
+        let full = quantize(&rgb, 8, 4, &ALL_COLORS, Dithering::None).unwrap();
+        let mut expected = full
+            .coverage()
+            .iter()
+            .map(|c| c.color.code)
+            .collect::<Vec<_>>();
+        let mut actual = selection.palette().map(|c| c.code).collect::<Vec<_>>();
+        expected.sort();
+        actual.sort();
+        assert_eq!(actual, expected);
+
+        let quantized = quantize(&rgb, 8, 4, selection.palette(), Dithering::None).unwrap();
+        assert_eq!(quantized.to_rgb_bytes(), full.to_rgb_bytes());
+        for (selected, coverage) in selection.colors.iter().zip(quantized.coverage()) {
+            assert_eq!(selected.color, coverage.color);
+            assert_eq!(selected.ratio, coverage.ratio);
+        }
+    }
+
+    #[test]
+    fn empty_image() {
+        for n in [1, 2, ALL_COLORS.len() + 1] {
+            let selection = select_colors(&[], 0, 0, &ALL_COLORS, n).unwrap();
+            assert!(selection.colors.is_empty());
+            assert_eq!(selection.mean_delta_e, 0.0);
+            assert_eq!(selection.max_delta_e, 0.0);
+        }
+    }
+
+    #[test]
+    fn invalid_inputs() {
+        let rgb = red_blue(2, 2);
+        assert_eq!(
+            quantize(&rgb, 3, 2, &ALL_COLORS, Dithering::None),
+            Err(Error::InvalidBufferSize)
+        );
+        assert_eq!(
+            quantize(&rgb, 2, 2, &[], Dithering::None),
+            Err(Error::EmptyPalette)
+        );
+        assert_eq!(
+            select_colors(&rgb, 2, 2, &ALL_COLORS, 0),
+            Err(Error::EmptyPalette)
+        );
+    }
+}
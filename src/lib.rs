@@ -3,10 +3,70 @@
 //! About Copic colors, please refer to the following documents:
 //! - <https://copic.too.com/blogs/educational/copic-color-system>
 //! - <https://copic.too.com/blogs/educational/how-are-copic-colors-organized-and-named>
+//!
+//! Color-space conversions (`Lab`, `Oklab`, `delta_e`, etc.) need floating-point functions,
+//! which are provided by the `std` feature or by the `libm` feature (enabled by default).
+//! Without either of them, only the color table and the features not relying on them are available.
 #![no_std]
 
+#[cfg(feature = "image")]
+extern crate alloc;
+#[cfg(any(feature = "std", test))]
+extern crate std;
+
+#[cfg(feature = "std")]
+pub mod ase;
+#[cfg(all(feature = "image", any(feature = "std", feature = "libm")))]
+pub mod color_by_number;
+pub mod css;
+#[cfg(any(feature = "std", feature = "libm"))]
+pub mod delta_e;
+#[cfg(all(feature = "image", any(feature = "std", feature = "libm")))]
+pub mod image;
+#[cfg(feature = "std")]
+pub mod palette_export;
+#[cfg(feature = "serde")]
+pub mod serde_rgb;
+
+mod blend;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod cie;
+mod code;
+mod color_id;
+mod color_set;
+mod family;
+mod group_value;
+mod lookup;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod math;
+mod nearest;
+#[cfg(any(feature = "std", feature = "libm"))]
+mod oklab;
+mod query;
+mod rgb_fmt;
+mod search;
+#[cfg(feature = "serde")]
+mod serde_impls;
+#[cfg(feature = "std")]
+mod zip;
+
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use cie::{Lab, Lch, LinearRgb, Xyz, D65_WHITE};
+pub use code::{CopicCode, GrayTone, ParseCodeError};
+pub use color_id::{ColorId, UnknownColorIdError};
+pub use color_set::{ColorSet, ColorSetIter, DecodeColorSetError, COLOR_SET_BYTES};
+pub use group_value::InvalidDigitsError;
+pub use lookup::lookup;
+pub use nearest::{k_nearest, k_nearest_in, nearest, nearest_in, Match};
+#[cfg(any(feature = "std", feature = "libm"))]
+pub use oklab::{Oklab, Oklch};
+pub use query::{Query, QueryIter};
+pub use rgb_fmt::ParseRgbError;
+pub use search::{search, search_in, MatchKind, SearchHit};
+
 /// Copic color.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Color {
     pub code: &'static str,
     pub name: &'static str,
@@ -34,6 +94,65 @@ impl Color {
             rgb,
         }
     }
+
+    /// Finds the color having the given code.
+    ///
+    /// The lookup is case-insensitive and ignores whitespaces, hyphens and underscores,
+    /// so "C-3", "C3", "c-3" and "c 3" all resolve to [`COLOR_C_3`].
+    pub fn from_code(code: &str) -> Option<Self> {
+        if let Some(color) = lookup(code) {
+            return Some(*color);
+        }
+        ALL_COLORS.iter().find(|c| code_eq(c.code, code)).copied()
+    }
+}
+
+impl core::str::FromStr for Color {
+    type Err = ParseColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.trim().is_empty() {
+            return Err(ParseColorError::Empty);
+        }
+        Self::from_code(s).ok_or(ParseColorError::UnknownCode)
+    }
+}
+
+/// Error returned when parsing a [`Color`] from a code fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseColorError {
+    /// The input string is empty.
+    Empty,
+
+    /// The input string does not match any known color code.
+    UnknownCode,
+}
+
+impl core::fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty color code"),
+            Self::UnknownCode => write!(f, "unknown color code"),
+        }
+    }
+}
+
+impl core::error::Error for ParseColorError {}
+
+fn code_eq(canonical: &str, input: &str) -> bool {
+    let mut a = canonical.bytes().filter(|b| !is_code_separator(*b));
+    let mut b = input.bytes().filter(|b| !is_code_separator(*b));
+    loop {
+        match (a.next(), b.next()) {
+            (None, None) => return true,
+            (Some(x), Some(y)) if x.eq_ignore_ascii_case(&y) => {}
+            _ => return false,
+        }
+    }
+}
+
+fn is_code_separator(b: u8) -> bool {
+    b.is_ascii_whitespace() || b == b'-' || b == b'_'
 }
 
 /// RGB.
@@ -52,6 +171,7 @@ impl Rgb {
 
 /// Colro family (aka. hue).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Family {
     BlueViolet,
     Violet,
@@ -73,7 +193,11 @@ pub enum Family {
 }
 
 /// Color blending group (aka. saturation).
+///
+/// Note that the derived [`Ord`] treats [`Group::Undefined`] as the lowest group;
+/// use [`Group::checked_cmp()`] to exclude it.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Group {
     Undefined,
     S0,
@@ -89,7 +213,11 @@ pub enum Group {
 }
 
 /// Color intensity value (aka. brightness).
+///
+/// Note that the derived [`Ord`] treats [`Value::Undefined`] as the lightest value;
+/// use [`Value::checked_cmp()`] to exclude it.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub enum Value {
     Undefined,
     B000,
@@ -3689,3 +3817,31 @@ pub const ALL_COLORS: [Color; 358] = [
     COLOR_FBG,
     COLOR_FB,
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_code_variants() {
+        for s in ["C-3", "C3", "c-3", "c 3", " c_3 "] {
+            assert_eq!(s.parse(), Ok(COLOR_C_3), "{s:?}");
+        }
+        assert_eq!("bv 04".parse(), Ok(COLOR_BV04));
+        assert_eq!("FBG".parse(), Ok(COLOR_FBG));
+        assert_eq!("fbg".parse(), Ok(COLOR_FBG));
+        assert_eq!("0".parse(), Ok(COLOR_0));
+        for color in &ALL_COLORS {
+            assert_eq!(color.code.parse(), Ok(*color));
+        }
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!("".parse::<Color>(), Err(ParseColorError::Empty));
+        assert_eq!("  ".parse::<Color>(), Err(ParseColorError::Empty));
+        assert_eq!("BV05".parse::<Color>(), Err(ParseColorError::UnknownCode));
+        assert_eq!("C-3x".parse::<Color>(), Err(ParseColorError::UnknownCode));
+        assert_eq!(Color::from_code("X1"), None);
+    }
+}
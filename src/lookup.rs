@@ -0,0 +1,63 @@
+use crate::{Color, ALL_COLORS};
+
+const COLORS: &[Color] = &ALL_COLORS;
+
+/// Indices of [`ALL_COLORS`] sorted by [`Color::code`], built at compile time.
+static CODE_INDEX: [u16; ALL_COLORS.len()] = sorted_code_index();
+
+/// Finds the color having exactly the given code.
+///
+/// Unlike [`Color::from_code`], this function only accepts canonical codes (e.g., "R29", "C-3")
+/// and runs a binary search over a table built at compile time, so no runtime initialization is required.
+pub fn lookup(code: &str) -> Option<&'static Color> {
+    index_of(code).map(|i| &COLORS[i])
+}
+
+/// Returns the position in [`ALL_COLORS`] of the color having exactly the given code.
+pub(crate) fn index_of(code: &str) -> Option<usize> {
+    CODE_INDEX
+        .binary_search_by(|&i| COLORS[usize::from(i)].code.cmp(code))
+        .ok()
+        .map(|i| usize::from(CODE_INDEX[i]))
+}
+
+const fn sorted_code_index() -> [u16; ALL_COLORS.len()] {
+    let mut index = [0; ALL_COLORS.len()];
+    let mut i = 0;
+    while i < index.len() {
+        index[i] = i as u16;
+        i += 1;
+    }
+
+    // Insertion sort (the table is small and this runs only at compile time).
+    let mut i = 1;
+    while i < index.len() {
+        let mut j = i;
+        while j > 0
+            && str_lt(
+                COLORS[index[j] as usize].code,
+                COLORS[index[j - 1] as usize].code,
+            )
+        {
+            let tmp = index[j];
+            index[j] = index[j - 1];
+            index[j - 1] = tmp;
+            j -= 1;
+        }
+        i += 1;
+    }
+    index
+}
+
+const fn str_lt(a: &str, b: &str) -> bool {
+    let a = a.as_bytes();
+    let b = b.as_bytes();
+    let mut i = 0;
+    while i < a.len() && i < b.len() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+        i += 1;
+    }
+    a.len() < b.len()
+}
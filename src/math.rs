@@ -0,0 +1,55 @@
+//! Floating-point functions backed by `std` if the `std` feature is enabled, or by `libm` otherwise.
+//!
+//! The names follow `libm` (`f` suffixed functions take `f32`).
+macro_rules! unary {
+    ($($name:ident: $ty:ty => $method:ident;)*) => {
+        $(
+            #[cfg(feature = "std")]
+            pub fn $name(x: $ty) -> $ty {
+                x.$method()
+            }
+
+            #[cfg(not(feature = "std"))]
+            pub fn $name(x: $ty) -> $ty {
+                libm::$name(x)
+            }
+        )*
+    };
+}
+
+macro_rules! binary {
+    ($($name:ident: $ty:ty => $method:ident;)*) => {
+        $(
+            #[cfg(feature = "std")]
+            pub fn $name(x: $ty, y: $ty) -> $ty {
+                x.$method(y)
+            }
+
+            #[cfg(not(feature = "std"))]
+            pub fn $name(x: $ty, y: $ty) -> $ty {
+                libm::$name(x, y)
+            }
+        )*
+    };
+}
+
+unary! {
+    cbrtf: f32 => cbrt;
+    cosf: f32 => cos;
+    roundf: f32 => round;
+    sinf: f32 => sin;
+    sqrtf: f32 => sqrt;
+    cos: f64 => cos;
+    exp: f64 => exp;
+    sin: f64 => sin;
+    sqrt: f64 => sqrt;
+}
+
+binary! {
+    atan2f: f32 => atan2;
+    hypotf: f32 => hypot;
+    powf: f32 => powf;
+    atan2: f64 => atan2;
+    hypot: f64 => hypot;
+    pow: f64 => powf;
+}
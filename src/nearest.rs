@@ -0,0 +1,87 @@
+use crate::{Color, Rgb, ALL_COLORS};
+
+/// A color found by [`nearest()`] or [`k_nearest()`] together with its distance from the query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Match<'a> {
+    pub color: &'a Color,
+
+    /// Squared "redmean" distance between the query and [`Match::color`] (`0` means identical).
+    ///
+    /// See [`Rgb::redmean_distance()`] for the details.
+    pub distance: u32,
+}
+
+/// Returns the color in [`ALL_COLORS`] that is perceptually nearest to `rgb`.
+pub fn nearest(rgb: Rgb) -> Match<'static> {
+    let colors: &'static [Color] = &ALL_COLORS;
+    nearest_in(rgb, colors).unwrap_or_else(|| unreachable!())
+}
+
+/// Returns the color in `candidates` that is perceptually nearest to `rgb`.
+///
+/// Returns `None` if `candidates` is empty.
+/// If several candidates have the same distance, the first one wins.
+pub fn nearest_in<'a, I>(rgb: Rgb, candidates: I) -> Option<Match<'a>>
+where
+    I: IntoIterator<Item = &'a Color>,
+{
+    candidates
+        .into_iter()
+        .map(|color| Match::new(rgb, color))
+        .min_by_key(|m| m.distance)
+}
+
+/// Returns the `K` colors in [`ALL_COLORS`] that are perceptually nearest to `rgb`, nearest first.
+pub fn k_nearest<const K: usize>(rgb: Rgb) -> [Option<Match<'static>>; K] {
+    let colors: &'static [Color] = &ALL_COLORS;
+    k_nearest_in(rgb, colors)
+}
+
+/// Returns the `K` colors in `candidates` that are perceptually nearest to `rgb`, nearest first.
+///
+/// If `candidates` has fewer than `K` colors, the trailing elements of the result are `None`.
+pub fn k_nearest_in<'a, I, const K: usize>(rgb: Rgb, candidates: I) -> [Option<Match<'a>>; K]
+where
+    I: IntoIterator<Item = &'a Color>,
+{
+    let mut matches: [Option<Match>; K] = [None; K];
+    for color in candidates {
+        let mut m = Match::new(rgb, color);
+        for slot in &mut matches {
+            match slot {
+                Some(x) if x.distance <= m.distance => {}
+                Some(x) => core::mem::swap(x, &mut m),
+                None => {
+                    *slot = Some(m);
+                    break;
+                }
+            }
+        }
+    }
+    matches
+}
+
+impl<'a> Match<'a> {
+    fn new(rgb: Rgb, color: &'a Color) -> Self {
+        Self {
+            color,
+            distance: rgb.redmean_distance(color.rgb),
+        }
+    }
+}
+
+impl Rgb {
+    /// Returns the squared "redmean" distance between two RGB values.
+    ///
+    /// This is a weighted Euclidean distance in the sRGB space that approximates
+    /// perceptual differences well without any floating point math
+    /// (see <https://www.compuphase.com/cmetric.htm>).
+    pub const fn redmean_distance(self, other: Self) -> u32 {
+        let rmean = (self.r as u32 + other.r as u32) / 2;
+        let dr = self.r.abs_diff(other.r) as u32;
+        let dg = self.g.abs_diff(other.g) as u32;
+        let db = self.b.abs_diff(other.b) as u32;
+        ((512 + rmean) * dr * dr + 1024 * dg * dg + (767 - rmean) * db * db) >> 8
+    }
+}
@@ -0,0 +1,518 @@
+use crate::math;
+use crate::{Color, LinearRgb, Rgb};
+
+/// OKLab color (see <https://bottosson.github.io/posts/oklab/>).
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Oklab {
+    /// Perceived lightness (`0.0..=1.0`).
+    pub l: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+impl Oklab {
+    /// Makes a new [`Oklab`] instance.
+    pub const fn new(l: f32, a: f32, b: f32) -> Self {
+        Self { l, a, b }
+    }
+
+    /// Converts to linear sRGB.
+    ///
+    /// The result may be out of the range `0.0..=1.0` if the color is out of the sRGB gamut.
+    pub fn to_linear_rgb(self) -> LinearRgb {
+        let l = self.l + 0.396_337_78 * self.a + 0.215_803_76 * self.b;
+        let m = self.l - 0.105_561_346 * self.a - 0.063_854_17 * self.b;
+        let s = self.l - 0.089_484_18 * self.a - 1.291_485_5 * self.b;
+        let (l, m, s) = (l * l * l, m * m * m, s * s * s);
+        LinearRgb::new(
+            4.076_741_7 * l - 3.307_711_6 * m + 0.230_969_94 * s,
+            -1.268_438 * l + 2.609_757_4 * m - 0.341_319_4 * s,
+            -0.004_196_086_3 * l - 0.703_418_6 * m + 1.707_614_7 * s,
+        )
+    }
+
+    /// Converts to sRGB.
+    ///
+    /// Out-of-gamut colors are clamped.
+    pub fn to_rgb(self) -> Rgb {
+        self.to_linear_rgb().to_rgb()
+    }
+
+    /// Converts to OKLCh.
+    pub fn to_oklch(self) -> Oklch {
+        let c = math::hypotf(self.a, self.b);
+        let h = math::atan2f(self.b, self.a).to_degrees();
+        Oklch::new(self.l, c, if h < 0.0 { h + 360.0 } else { h })
+    }
+}
+
+/// OKLCh, the cylindrical representation of [`Oklab`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Oklch {
+    /// Perceived lightness (`0.0..=1.0`).
+    pub l: f32,
+
+    /// Chroma.
+    pub c: f32,
+
+    /// Hue angle in degrees (`0.0..360.0`).
+    pub h: f32,
+}
+
+impl Oklch {
+    /// Makes a new [`Oklch`] instance.
+    pub const fn new(l: f32, c: f32, h: f32) -> Self {
+        Self { l, c, h }
+    }
+
+    /// Converts to OKLab.
+    pub fn to_oklab(self) -> Oklab {
+        let h = self.h.to_radians();
+        Oklab::new(self.l, self.c * math::cosf(h), self.c * math::sinf(h))
+    }
+
+    /// Converts to sRGB.
+    ///
+    /// Out-of-gamut colors are clamped.
+    pub fn to_rgb(self) -> Rgb {
+        self.to_oklab().to_rgb()
+    }
+}
+
+impl LinearRgb {
+    /// Converts to OKLab.
+    pub fn to_oklab(self) -> Oklab {
+        let Self { r, g, b } = self;
+        let l = math::cbrtf(0.412_221_46 * r + 0.536_332_55 * g + 0.051_445_995 * b);
+        let m = math::cbrtf(0.211_903_5 * r + 0.680_699_5 * g + 0.107_396_96 * b);
+        let s = math::cbrtf(0.088_302_46 * r + 0.281_718_85 * g + 0.629_978_7 * b);
+        Oklab::new(
+            0.210_454_26 * l + 0.793_617_8 * m - 0.004_072_047 * s,
+            1.977_998_5 * l - 2.428_592_2 * m + 0.450_593_7 * s,
+            0.025_904_037 * l + 0.782_771_77 * m - 0.808_675_77 * s,
+        )
+    }
+}
+
+impl Rgb {
+    /// Converts to OKLab.
+    pub fn to_oklab(self) -> Oklab {
+        self.to_linear_rgb().to_oklab()
+    }
+
+    /// Converts to OKLCh.
+    pub fn to_oklch(self) -> Oklch {
+        self.to_oklab().to_oklch()
+    }
+}
+
+impl From<Rgb> for Oklab {
+    fn from(rgb: Rgb) -> Self {
+        rgb.to_oklab()
+    }
+}
+
+impl Color {
+    /// Returns the OKLab coordinates of this color.
+    ///
+    /// For colors in [`ALL_COLORS`](crate::ALL_COLORS), this returns a value precomputed at build time.
+    pub fn oklab(&self) -> Oklab {
+        match crate::lookup::index_of(self.code) {
+            Some(i) if crate::ALL_COLORS[i].rgb == self.rgb => ALL_OKLABS[i],
+            _ => self.rgb.to_oklab(),
+        }
+    }
+
+    /// Returns the OKLCh coordinates of this color.
+    pub fn oklch(&self) -> Oklch {
+        self.oklab().to_oklch()
+    }
+}
+
+/// OKLab coordinates of [`ALL_COLORS`](crate::ALL_COLORS) (in the same order).
+static ALL_OKLABS: [Oklab; crate::ALL_COLORS.len()] = [
+    Oklab::new(0.947220, 0.005405, -0.010914),  // BV0000
+    Oklab::new(0.936316, 0.010971, -0.010608),  // BV000
+    Oklab::new(0.924313, 0.011064, -0.014869),  // BV00
+    Oklab::new(0.831994, 0.014309, -0.034783),  // BV01
+    Oklab::new(0.824229, 0.003750, -0.038196),  // BV02
+    Oklab::new(0.719727, -0.003757, -0.064623), // BV04
+    Oklab::new(0.688802, 0.057941, -0.049549),  // BV08
+    Oklab::new(0.902369, 0.010132, -0.019555),  // BV11
+    Oklab::new(0.709692, 0.009283, -0.062399),  // BV13
+    Oklab::new(0.656104, -0.003015, -0.070516), // BV17
+    Oklab::new(0.914969, -0.001611, -0.021712), // BV20
+    Oklab::new(0.847585, -0.001886, -0.029471), // BV23
+    Oklab::new(0.660884, 0.012314, -0.040919),  // BV25
+    Oklab::new(0.371136, -0.015353, -0.040100), // BV29
+    Oklab::new(0.932975, 0.002410, -0.016127),  // BV31
+    Oklab::new(0.661084, -0.004687, -0.067801), // BV34
+    Oklab::new(0.961678, 0.004650, -0.008307),  // V0000
+    Oklab::new(0.966420, 0.006986, -0.004776),  // V000
+    Oklab::new(0.878244, 0.042114, -0.012052),  // V01
+    Oklab::new(0.839249, 0.066042, -0.012545),  // V04
+    Oklab::new(0.828338, 0.071513, -0.013535),  // V05
+    Oklab::new(0.781929, 0.072817, -0.023962),  // V06
+    Oklab::new(0.558172, 0.098857, -0.068248),  // V09
+    Oklab::new(0.922376, 0.024393, -0.009171),  // V12
+    Oklab::new(0.821367, 0.056214, -0.020718),  // V15
+    Oklab::new(0.737129, 0.034118, -0.046170),  // V17
+    Oklab::new(0.855402, 0.013355, -0.010172),  // V20
+    Oklab::new(0.564779, 0.027071, -0.044393),  // V22
+    Oklab::new(0.765080, 0.017858, -0.030008),  // V25
+    Oklab::new(0.592090, 0.012155, -0.056590),  // V28
+    Oklab::new(0.885785, 0.036156, 0.000298),   // V91
+    Oklab::new(0.880423, 0.040993, -0.012422),  // V93
+    Oklab::new(0.699572, 0.082070, -0.021388),  // V95
+    Oklab::new(0.364381, 0.049118, -0.029334),  // V99
+    Oklab::new(0.959549, 0.009803, -0.006695),  // RV0000
+    Oklab::new(0.947350, 0.017503, -0.005675),  // RV000
+    Oklab::new(0.899961, 0.042192, -0.017617),  // RV00
+    Oklab::new(0.928346, 0.035729, -0.003971),  // RV02
+    Oklab::new(0.831819, 0.088855, 0.000573),   // RV04
+    Oklab::new(0.743534, 0.133960, -0.008464),  // RV06
+    Oklab::new(0.720336, 0.142137, -0.016805),  // RV09
+    Oklab::new(0.969444, 0.014885, -0.000880),  // RV10
+    Oklab::new(0.928533, 0.032063, 0.004669),   // RV11
+    Oklab::new(0.904713, 0.047941, 0.000122),   // RV13
+    Oklab::new(0.801014, 0.109389, -0.001396),  // RV14
+    Oklab::new(0.742820, 0.118734, -0.018856),  // RV17
+    Oklab::new(0.692665, 0.143464, -0.022507),  // RV19
+    Oklab::new(0.961481, 0.016188, 0.006477),   // RV21
+    Oklab::new(0.875731, 0.060916, 0.007507),   // RV23
+    Oklab::new(0.804159, 0.109574, -0.007280),  // RV25
+    Oklab::new(0.658210, 0.205119, 0.017442),   // RV29
+    Oklab::new(0.920502, 0.033053, 0.017605),   // RV32
+    Oklab::new(0.851557, 0.067650, 0.028524),   // RV34
+    Oklab::new(0.873337, 0.053375, 0.028919),   // RV42
+    Oklab::new(0.761794, 0.082400, -0.009885),  // RV52
+    Oklab::new(0.655108, 0.159564, -0.014598),  // RV55
+    Oklab::new(0.729603, 0.109162, -0.024901),  // RV63
+    Oklab::new(0.470420, 0.125914, -0.022392),  // RV66
+    Oklab::new(0.565418, 0.079268, -0.003164),  // RV69
+    Oklab::new(0.915462, 0.019457, -0.007971),  // RV91
+    Oklab::new(0.858808, 0.052333, -0.004267),  // RV93
+    Oklab::new(0.720533, 0.065729, -0.010678),  // RV95
+    Oklab::new(0.484445, 0.034426, -0.010151),  // RV99
+    Oklab::new(0.976670, 0.004643, 0.006965),   // R0000
+    Oklab::new(0.979023, 0.007729, 0.007927),   // R000
+    Oklab::new(0.976285, 0.008016, 0.010776),   // R00
+    Oklab::new(0.928856, 0.028289, 0.017398),   // R01
+    Oklab::new(0.919582, 0.031459, 0.026796),   // R02
+    Oklab::new(0.782470, 0.093402, 0.066691),   // R05
+    Oklab::new(0.694792, 0.145590, 0.089419),   // R08
+    Oklab::new(0.946577, 0.019852, 0.021511),   // R11
+    Oklab::new(0.918340, 0.029535, 0.033051),   // R12
+    Oklab::new(0.807589, 0.086402, 0.045809),   // R14
+    Oklab::new(0.754479, 0.107152, 0.079099),   // R17
+    Oklab::new(0.929460, 0.028976, 0.019012),   // R20
+    Oklab::new(0.838913, 0.073495, 0.039219),   // R21
+    Oklab::new(0.788439, 0.115074, 0.042308),   // R22
+    Oklab::new(0.730129, 0.136149, 0.048718),   // R24
+    Oklab::new(0.662160, 0.184452, 0.060283),   // R27
+    Oklab::new(0.603577, 0.230200, 0.073867),   // R29
+    Oklab::new(0.951663, 0.018934, 0.012915),   // R30
+    Oklab::new(0.884778, 0.048753, 0.027252),   // R32
+    Oklab::new(0.724655, 0.146829, 0.035151),   // R35
+    Oklab::new(0.707968, 0.136646, 0.046493),   // R37
+    Oklab::new(0.626643, 0.169776, 0.002937),   // R39
+    Oklab::new(0.754691, 0.114505, 0.032215),   // R43
+    Oklab::new(0.642013, 0.180992, 0.040362),   // R46
+    Oklab::new(0.623271, 0.118487, 0.017038),   // R56
+    Oklab::new(0.616299, 0.132812, 0.008846),   // R59
+    Oklab::new(0.900640, 0.039251, 0.001387),   // R81
+    Oklab::new(0.815496, 0.091604, 0.001609),   // R83
+    Oklab::new(0.692914, 0.134596, -0.001926),  // R85
+    Oklab::new(0.465947, 0.124598, 0.016051),   // R89
+    Oklab::new(0.974798, 0.001994, 0.015767),   // YR0000
+    Oklab::new(0.964908, 0.008388, 0.024636),   // YR000
+    Oklab::new(0.923088, 0.023525, 0.040606),   // YR00
+    Oklab::new(0.901385, 0.029646, 0.052209),   // YR01
+    Oklab::new(0.935430, 0.020317, 0.035409),   // YR02
+    Oklab::new(0.877253, 0.014543, 0.127213),   // YR04
+    Oklab::new(0.709443, 0.120714, 0.124898),   // YR07
+    Oklab::new(0.658878, 0.155578, 0.132951),   // YR09
+    Oklab::new(0.920281, -0.003446, 0.105591),  // YR12
+    Oklab::new(0.881768, -0.001132, 0.154686),  // YR14
+    Oklab::new(0.887523, 0.027262, 0.085132),   // YR15
+    Oklab::new(0.850162, 0.011824, 0.170196),   // YR16
+    Oklab::new(0.700898, 0.127511, 0.121228),   // YR18
+    Oklab::new(0.943646, 0.010647, 0.047098),   // YR20
+    Oklab::new(0.926825, 0.003540, 0.058328),   // YR21
+    Oklab::new(0.895073, -0.002016, 0.088722),  // YR23
+    Oklab::new(0.894135, -0.015449, 0.134722),  // YR24
+    Oklab::new(0.643531, 0.119497, 0.091489),   // YR27
+    Oklab::new(0.944837, -0.003591, 0.048062),  // YR30
+    Oklab::new(0.932903, 0.005994, 0.071890),   // YR31
+    Oklab::new(0.930015, 0.021032, 0.037037),   // YR61
+    Oklab::new(0.837495, 0.041215, 0.121863),   // YR65
+    Oklab::new(0.705878, 0.115054, 0.143007),   // YR68
+    Oklab::new(0.887523, 0.027262, 0.085132),   // YR82
+    Oklab::new(0.995871, -0.001604, 0.009116),  // Y0000
+    Oklab::new(0.997551, -0.003372, 0.011304),  // Y000
+    Oklab::new(0.991247, -0.008493, 0.031455),  // Y00
+    Oklab::new(0.954419, -0.029085, 0.101911),  // Y02
+    Oklab::new(0.915640, -0.044288, 0.159604),  // Y04
+    Oklab::new(0.957142, -0.041791, 0.147885),  // Y06
+    Oklab::new(0.947338, -0.058195, 0.194212),  // Y08
+    Oklab::new(0.983087, -0.012723, 0.051496),  // Y11
+    Oklab::new(0.968954, -0.022646, 0.080707),  // Y13
+    Oklab::new(0.938931, -0.029151, 0.142270),  // Y15
+    Oklab::new(0.928129, -0.031918, 0.161344),  // Y17
+    Oklab::new(0.944483, -0.042572, 0.164474),  // Y18
+    Oklab::new(0.934451, -0.043500, 0.178330),  // Y19
+    Oklab::new(0.961182, -0.002407, 0.054816),  // Y21
+    Oklab::new(0.941477, 0.002734, 0.060513),   // Y23
+    Oklab::new(0.912710, -0.027138, 0.137011),  // Y26
+    Oklab::new(0.785674, 0.006869, 0.097032),   // Y28
+    Oklab::new(0.934682, 0.011182, 0.041995),   // Y32
+    Oklab::new(0.912917, -0.003816, 0.119735),  // Y35
+    Oklab::new(0.978353, -0.039475, 0.118013),  // Y38
+    Oklab::new(0.975016, -0.010039, 0.025720),  // YG0000
+    Oklab::new(0.926371, -0.024095, 0.084648),  // YG00
+    Oklab::new(0.947478, -0.020466, 0.050999),  // YG01
+    Oklab::new(0.931614, -0.027768, 0.072392),  // YG03
+    Oklab::new(0.910472, -0.038997, 0.094189),  // YG05
+    Oklab::new(0.886248, -0.046594, 0.086696),  // YG06
+    Oklab::new(0.813526, -0.085254, 0.152401),  // YG07
+    Oklab::new(0.765890, -0.092757, 0.107327),  // YG09
+    Oklab::new(0.950996, -0.018743, 0.035490),  // YG11
+    Oklab::new(0.910506, -0.036947, 0.079007),  // YG13
+    Oklab::new(0.734058, -0.116393, 0.118337),  // YG17
+    Oklab::new(0.967614, -0.018399, 0.061736),  // YG21
+    Oklab::new(0.932911, -0.034197, 0.103195),  // YG23
+    Oklab::new(0.891278, -0.048234, 0.115691),  // YG25
+    Oklab::new(0.933682, -0.021360, 0.022663),  // YG41
+    Oklab::new(0.877726, -0.041055, 0.035372),  // YG45
+    Oklab::new(0.810147, -0.043759, 0.067579),  // YG61
+    Oklab::new(0.831955, -0.045661, 0.041547),  // YG63
+    Oklab::new(0.777249, -0.068166, 0.052833),  // YG67
+    Oklab::new(0.897686, -0.010142, 0.050662),  // YG91
+    Oklab::new(0.880852, -0.017977, 0.066974),  // YG93
+    Oklab::new(0.849603, -0.032244, 0.125246),  // YG95
+    Oklab::new(0.692166, -0.038384, 0.141856),  // YG97
+    Oklab::new(0.542037, -0.058092, 0.111382),  // YG99
+    Oklab::new(0.980974, -0.005556, 0.003815),  // G0000
+    Oklab::new(0.983961, -0.005552, 0.003812),  // G000
+    Oklab::new(0.962217, -0.010766, -0.004675), // G00
+    Oklab::new(0.925225, -0.024642, 0.019022),  // G02
+    Oklab::new(0.846534, -0.085060, 0.101601),  // G03
+    Oklab::new(0.745005, -0.096510, 0.072765),  // G05
+    Oklab::new(0.769105, -0.089095, 0.084809),  // G07
+    Oklab::new(0.761026, -0.095327, 0.105852),  // G09
+    Oklab::new(0.923907, -0.027203, 0.037365),  // G12
+    Oklab::new(0.819398, -0.066334, 0.066832),  // G14
+    Oklab::new(0.751489, -0.088041, 0.029279),  // G16
+    Oklab::new(0.668954, -0.128262, 0.038930),  // G17
+    Oklab::new(0.701287, -0.115084, 0.033922),  // G19
+    Oklab::new(0.966245, -0.013894, 0.027340),  // G20
+    Oklab::new(0.909682, -0.029541, 0.020476),  // G21
+    Oklab::new(0.894027, -0.035747, 0.046064),  // G24
+    Oklab::new(0.598750, -0.117122, 0.043307),  // G28
+    Oklab::new(0.564650, -0.084081, 0.025447),  // G29
+    Oklab::new(0.956515, -0.014637, 0.019020),  // G40
+    Oklab::new(0.722503, -0.071404, 0.097094),  // G43
+    Oklab::new(0.580615, -0.075042, 0.045880),  // G46
+    Oklab::new(0.899496, -0.019022, 0.035936),  // G82
+    Oklab::new(0.822247, -0.036539, 0.023453),  // G85
+    Oklab::new(0.749798, -0.025081, 0.045670),  // G94
+    Oklab::new(0.614028, -0.055129, 0.092103),  // G99
+    Oklab::new(0.978261, -0.005184, 0.002553),  // BG0000
+    Oklab::new(0.984514, -0.004801, 0.001285),  // BG000
+    Oklab::new(0.928498, -0.016381, -0.023268), // BG01
+    Oklab::new(0.923771, -0.022482, -0.009577), // BG02
+    Oklab::new(0.839623, -0.049475, -0.038932), // BG05
+    Oklab::new(0.724125, -0.095484, -0.064766), // BG07
+    Oklab::new(0.699874, -0.100766, -0.069213), // BG09
+    Oklab::new(0.954908, -0.012542, -0.002428), // BG10
+    Oklab::new(0.935543, -0.018706, -0.011261), // BG11
+    Oklab::new(0.920445, -0.022879, -0.008289), // BG13
+    Oklab::new(0.866982, -0.042877, -0.002631), // BG15
+    Oklab::new(0.737154, -0.097310, -0.010182), // BG18
+    Oklab::new(0.906933, -0.028995, 0.001181),  // BG23
+    Oklab::new(0.902765, -0.030590, 0.006336),  // BG32
+    Oklab::new(0.872528, -0.039345, -0.007332), // BG34
+    Oklab::new(0.889264, -0.032851, -0.011181), // BG45
+    Oklab::new(0.696392, -0.107152, -0.043572), // BG49
+    Oklab::new(0.723643, -0.080153, -0.032632), // BG53
+    Oklab::new(0.649273, -0.101041, -0.043703), // BG57
+    Oklab::new(0.939281, -0.013389, 0.011226),  // BG70
+    Oklab::new(0.765087, -0.052815, -0.019623), // BG72
+    Oklab::new(0.866982, -0.042877, -0.002631), // BG75
+    Oklab::new(0.526613, -0.052337, 0.001387),  // BG78
+    Oklab::new(0.843797, -0.002600, 0.027559),  // BG90
+    Oklab::new(0.846245, -0.007077, 0.012103),  // BG93
+    Oklab::new(0.735103, -0.030831, 0.017341),  // BG96
+    Oklab::new(0.705243, -0.041754, 0.016060),  // BG99
+    Oklab::new(0.985174, -0.003947, -0.005361), // B0000
+    Oklab::new(0.966400, -0.009122, -0.002789), // B000
+    Oklab::new(0.965064, -0.011012, -0.007529), // B00
+    Oklab::new(0.946854, -0.015438, -0.007478), // B01
+    Oklab::new(0.904216, -0.029333, -0.022915), // B02
+    Oklab::new(0.823035, -0.057219, -0.048405), // B04
+    Oklab::new(0.776698, -0.075492, -0.068258), // B05
+    Oklab::new(0.696828, -0.089491, -0.105619), // B06
+    Oklab::new(0.924037, -0.018006, -0.013886), // B12
+    Oklab::new(0.824481, -0.055725, -0.053819), // B14
+    Oklab::new(0.732475, -0.099400, -0.093845), // B16
+    Oklab::new(0.624726, -0.065943, -0.104920), // B18
+    Oklab::new(0.952532, -0.007815, -0.014967), // B21
+    Oklab::new(0.828642, -0.021492, -0.050092), // B23
+    Oklab::new(0.847551, -0.039070, -0.051939), // B24
+    Oklab::new(0.765871, -0.044150, -0.072120), // B26
+    Oklab::new(0.530655, -0.052651, -0.115461), // B28
+    Oklab::new(0.548786, -0.059105, -0.122464), // B29
+    Oklab::new(0.958542, -0.007644, -0.007911), // B32
+    Oklab::new(0.819343, -0.035987, -0.057258), // B34
+    Oklab::new(0.523564, -0.061392, -0.095847), // B37
+    Oklab::new(0.512970, -0.040507, -0.110103), // B39
+    Oklab::new(0.959713, -0.006160, -0.013042), // B41
+    Oklab::new(0.803454, -0.042234, -0.063604), // B45
+    Oklab::new(0.863771, -0.017388, -0.021021), // B52
+    Oklab::new(0.931463, 0.000361, -0.016768),  // B60
+    Oklab::new(0.827801, -0.000063, -0.040792), // B63
+    Oklab::new(0.571666, 0.005203, -0.101871),  // B66
+    Oklab::new(0.501913, -0.045229, -0.116303), // B69
+    Oklab::new(0.407903, 0.001919, -0.129934),  // B79
+    Oklab::new(0.929118, -0.006686, -0.007663), // B91
+    Oklab::new(0.825145, -0.025121, -0.036576), // B93
+    Oklab::new(0.737215, -0.031170, -0.048115), // B95
+    Oklab::new(0.571836, -0.047346, -0.059582), // B97
+    Oklab::new(0.404572, -0.043893, -0.089089), // B99
+    Oklab::new(0.987030, 0.002477, 0.010397),   // E0000
+    Oklab::new(0.982620, 0.004534, 0.011047),   // E000
+    Oklab::new(0.977951, 0.006223, 0.012959),   // E00
+    Oklab::new(0.968905, 0.010011, 0.015547),   // E01
+    Oklab::new(0.966191, 0.010300, 0.018392),   // E02
+    Oklab::new(0.868937, 0.038129, 0.006650),   // E04
+    Oklab::new(0.724712, 0.071177, 0.061654),   // E07
+    Oklab::new(0.663085, 0.104318, 0.072786),   // E08
+    Oklab::new(0.679191, 0.115141, 0.088270),   // E09
+    Oklab::new(0.960285, 0.010107, 0.026564),   // E11
+    Oklab::new(0.882017, 0.022037, 0.039105),   // E13
+    Oklab::new(0.866534, 0.039974, 0.078109),   // E15
+    Oklab::new(0.616328, 0.105310, 0.064639),   // E17
+    Oklab::new(0.525578, 0.075934, 0.046314),   // E18
+    Oklab::new(0.598238, 0.127421, 0.105944),   // E19
+    Oklab::new(0.945757, 0.014160, 0.036087),   // E21
+    Oklab::new(0.586886, 0.064701, 0.067575),   // E23
+    Oklab::new(0.804007, 0.031282, 0.059168),   // E25
+    Oklab::new(0.649875, 0.033957, 0.048085),   // E27
+    Oklab::new(0.468236, 0.084872, 0.090677),   // E29
+    Oklab::new(0.950166, -0.004094, 0.042528),  // E30
+    Oklab::new(0.945391, 0.002302, 0.029681),   // E31
+    Oklab::new(0.907999, 0.015462, 0.047624),   // E33
+    Oklab::new(0.895200, 0.017882, 0.055195),   // E34
+    Oklab::new(0.879045, 0.017385, 0.049872),   // E35
+    Oklab::new(0.756986, 0.033543, 0.095399),   // E37
+    Oklab::new(0.669012, 0.072543, 0.115252),   // E39
+    Oklab::new(0.957593, 0.003038, 0.016156),   // E40
+    Oklab::new(0.972507, 0.006782, 0.018634),   // E41
+    Oklab::new(0.933318, 0.002309, 0.029770),   // E42
+    Oklab::new(0.925800, -0.000047, 0.037265),  // E43
+    Oklab::new(0.839710, 0.004250, 0.028293),   // E44
+    Oklab::new(0.622913, 0.018730, 0.039117),   // E47
+    Oklab::new(0.501762, 0.025477, 0.045619),   // E49
+    Oklab::new(0.960849, 0.007573, 0.000968),   // E50
+    Oklab::new(0.964155, 0.007233, 0.028362),   // E51
+    Oklab::new(0.942745, -0.002013, 0.043231),  // E53
+    Oklab::new(0.928270, 0.002460, 0.047411),   // E55
+    Oklab::new(0.712130, 0.025925, 0.075563),   // E57
+    Oklab::new(0.673112, 0.022509, 0.045499),   // E59
+    Oklab::new(0.938360, 0.005629, 0.011499),   // E70
+    Oklab::new(0.915726, 0.007767, 0.012227),   // E71
+    Oklab::new(0.704513, 0.026490, 0.027783),   // E74
+    Oklab::new(0.576632, 0.031003, 0.049385),   // E77
+    Oklab::new(0.367472, 0.030060, 0.028876),   // E79
+    Oklab::new(0.847865, -0.005190, 0.071080),  // E81
+    Oklab::new(0.685853, 0.013106, 0.079562),   // E84
+    Oklab::new(0.428503, 0.008084, 0.036663),   // E87
+    Oklab::new(0.562256, 0.003917, 0.016939),   // E89
+    Oklab::new(0.914293, 0.027531, 0.043328),   // E93
+    Oklab::new(0.864887, 0.035308, 0.095061),   // E95
+    Oklab::new(0.792136, 0.055695, 0.110364),   // E97
+    Oklab::new(0.601595, 0.081428, 0.120078),   // E99
+    Oklab::new(0.965442, -0.002310, -0.003489), // C-00
+    Oklab::new(0.957933, -0.004347, -0.004119), // C-0
+    Oklab::new(0.933348, -0.005019, -0.005745), // C-1
+    Oklab::new(0.902511, -0.005708, -0.007406), // C-2
+    Oklab::new(0.870617, -0.003306, -0.008153), // C-3
+    Oklab::new(0.806484, -0.006142, -0.010616), // C-4
+    Oklab::new(0.744136, -0.009180, -0.008735), // C-5
+    Oklab::new(0.684898, -0.006384, -0.011056), // C-6
+    Oklab::new(0.591103, -0.007438, -0.008559), // C-7
+    Oklab::new(0.516952, -0.005347, -0.008144), // C-8
+    Oklab::new(0.436492, 0.002045, 0.002325),   // C-9
+    Oklab::new(0.236130, 0.001459, 0.000454),   // C-10
+    Oklab::new(0.961151, 0.0, 0.0),             // N-0
+    Oklab::new(0.940070, 0.0, 0.0),             // N-1
+    Oklab::new(0.915831, 0.0, 0.0),             // N-2
+    Oklab::new(0.894490, 0.0, 0.0),             // N-3
+    Oklab::new(0.835905, 0.0, 0.0),             // N-4
+    Oklab::new(0.777082, 0.001080, 0.000334),   // N-5
+    Oklab::new(0.718705, 0.0, 0.0),             // N-6
+    Oklab::new(0.614522, 0.001564, -0.001071),  // N-7
+    Oklab::new(0.539124, 0.001184, 0.000366),   // N-8
+    Oklab::new(0.436492, 0.002045, 0.002325),   // N-9
+    Oklab::new(0.236130, 0.001459, 0.000454),   // N-10
+    Oklab::new(0.961151, 0.0, 0.0),             // T-0
+    Oklab::new(0.954580, -0.000753, 0.002549),  // T-1
+    Oklab::new(0.933183, -0.001137, 0.003843),  // T-2
+    Oklab::new(0.893863, -0.000877, 0.006786),  // T-3
+    Oklab::new(0.833239, 0.000563, 0.005921),   // T-4
+    Oklab::new(0.774094, 0.001259, 0.007700),   // T-5
+    Oklab::new(0.712707, 0.000585, 0.006154),   // T-6
+    Oklab::new(0.610784, 0.001032, 0.004976),   // T-7
+    Oklab::new(0.535592, 0.001066, 0.005140),   // T-8
+    Oklab::new(0.433130, 0.002387, 0.005808),   // T-9
+    Oklab::new(0.236130, 0.001459, 0.000454),   // T-10
+    Oklab::new(0.974485, -0.002255, 0.007591),  // W-00
+    Oklab::new(0.972257, -0.001236, 0.007909),  // W-0
+    Oklab::new(0.945211, -0.001245, 0.007964),  // W-1
+    Oklab::new(0.945760, -0.000484, 0.005420),  // W-2
+    Oklab::new(0.893310, -0.001651, 0.009362),  // W-3
+    Oklab::new(0.834709, -0.001681, 0.009519),  // W-4
+    Oklab::new(0.776159, -0.000228, 0.008692),  // W-5
+    Oklab::new(0.714240, -0.001752, 0.009887),  // W-6
+    Oklab::new(0.613542, 0.000180, 0.007796),   // W-7
+    Oklab::new(0.537547, -0.001005, 0.007685),  // W-8
+    Oklab::new(0.432807, 0.001922, 0.007344),   // W-9
+    Oklab::new(0.235740, 0.000925, 0.002256),   // W-10
+    Oklab::new(1.000000, 0.0, 0.0),             // 0
+    Oklab::new(0.067205, 0.0, 0.0),             // 100
+    Oklab::new(0.067205, 0.0, 0.0),             // 110
+    Oklab::new(0.590680, 0.046001, -0.089666),  // FV
+    Oklab::new(0.833586, 0.090973, -0.007398),  // FRV
+    Oklab::new(0.901198, 0.022851, 0.076124),   // FYR
+    Oklab::new(0.967312, -0.028432, 0.106480),  // FY
+    Oklab::new(0.898614, -0.031380, 0.069392),  // FYG
+    Oklab::new(0.800296, -0.093646, 0.161250),  // FG
+    Oklab::new(0.807654, -0.063142, -0.055946), // FBG
+    Oklab::new(0.627352, -0.074402, -0.109859), // FB
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ALL_COLORS;
+
+    #[test]
+    fn precomputed_oklabs_match_conversion() {
+        for (color, oklab) in ALL_COLORS.iter().zip(&ALL_OKLABS) {
+            let expected = color.rgb.to_oklab();
+            for (x, y) in [
+                (oklab.l, expected.l),
+                (oklab.a, expected.a),
+                (oklab.b, expected.b),
+            ] {
+                assert!(
+                    (x - y).abs() < 1e-4,
+                    "{}: {oklab:?} != {expected:?}",
+                    color.code
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,401 @@
+//! Palette export for painting applications (GIMP/Inkscape, Krita and Procreate).
+use crate::zip::ZipWriter;
+use crate::{Color, Family, Group, Rgb, Value};
+use core::ops::RangeInclusive;
+use std::borrow::ToOwned;
+use std::io::{self, BufRead, Write};
+use std::string::String;
+use std::vec::Vec;
+
+/// Maximum number of swatches that a Procreate palette can hold.
+pub const PROCREATE_MAX_SWATCHES: usize = 30;
+
+/// Options controlling which colors are exported and in what order.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Options {
+    /// Palette name.
+    pub name: String,
+
+    /// If `Some(_)`, only colors belonging to one of these families are exported.
+    pub families: Option<Vec<Family>>,
+
+    /// If `Some(_)`, only colors whose group is in this range are exported.
+    pub groups: Option<RangeInclusive<Group>>,
+
+    /// If `Some(_)`, only colors whose value is in this range are exported.
+    pub values: Option<RangeInclusive<Value>>,
+
+    pub order: Order,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            name: "Copic".to_owned(),
+            families: None,
+            groups: None,
+            values: None,
+            order: Order::default(),
+        }
+    }
+}
+
+impl Options {
+    /// Returns the colors in `colors` matching these options, sorted by [`Options::order`].
+    pub fn select<'a>(&self, colors: &'a [Color]) -> Vec<&'a Color> {
+        let mut selected = colors
+            .iter()
+            .filter(|c| self.families.as_ref().is_none_or(|f| f.contains(&c.family)))
+            .filter(|c| self.groups.as_ref().is_none_or(|g| g.contains(&c.group)))
+            .filter(|c| self.values.as_ref().is_none_or(|v| v.contains(&c.value)))
+            .collect::<Vec<_>>();
+        match self.order {
+            Order::Catalogue => {}
+            Order::Code => selected.sort_by_key(|c| c.code),
+            Order::Lightness => selected.sort_by(|a, b| b.lab().l.total_cmp(&a.lab().l)),
+            Order::Hue => selected.sort_by(|a, b| a.lch().h.total_cmp(&b.lch().h)),
+        }
+        selected
+    }
+}
+
+/// Order of exported colors.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Order {
+    /// Same order as the input slice (e.g., [`ALL_COLORS`](crate::ALL_COLORS)).
+    #[default]
+    Catalogue,
+
+    /// Lexicographic order of [`Color::code`].
+    Code,
+
+    /// From lightest to darkest (CIELAB lightness).
+    Lightness,
+
+    /// By LCh(ab) hue angle.
+    Hue,
+}
+
+/// Writes a GIMP/Inkscape palette (.gpl).
+pub fn write_gpl<W: Write>(mut writer: W, colors: &[Color], options: &Options) -> io::Result<()> {
+    writeln!(writer, "GIMP Palette")?;
+    writeln!(writer, "Name: {}", options.name)?;
+    writeln!(writer, "Columns: 8")?;
+    writeln!(writer, "#")?;
+    for color in options.select(colors) {
+        let Rgb { r, g, b } = color.rgb;
+        writeln!(writer, "{r:3} {g:3} {b:3}\t{} {}", color.code, color.name)?;
+    }
+    Ok(())
+}
+
+/// Entry read from a GIMP palette by [`read_gpl()`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GplEntry {
+    pub rgb: Rgb,
+    pub name: String,
+
+    /// The Copic color whose code is the first word of [`GplEntry::name`], if any.
+    pub color: Option<Color>,
+}
+
+/// Reads a GIMP/Inkscape palette (.gpl).
+pub fn read_gpl<R: BufRead>(reader: R) -> io::Result<Vec<GplEntry>> {
+    let invalid_data = |message| io::Error::new(io::ErrorKind::InvalidData, message);
+
+    let mut lines = reader.lines();
+    if lines.next().transpose()?.as_deref().map(str::trim) != Some("GIMP Palette") {
+        return Err(invalid_data("missing 'GIMP Palette' header"));
+    }
+
+    let mut entries = Vec::new();
+    for line in lines {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty()
+            || line.starts_with('#')
+            || line.starts_with("Name:")
+            || line.starts_with("Columns:")
+        {
+            continue;
+        }
+
+        let mut rest = line;
+        let mut component = || {
+            let s = rest.trim_start();
+            let end = s.find(char::is_whitespace).unwrap_or(s.len());
+            rest = &s[end..];
+            s[..end]
+                .parse::<u8>()
+                .map_err(|_| invalid_data("invalid color component"))
+        };
+        let rgb = Rgb::new(component()?, component()?, component()?);
+        let name = rest.trim().to_owned();
+        let color = name
+            .split_whitespace()
+            .next()
+            .and_then(|code| crate::lookup(code).copied());
+        entries.push(GplEntry { rgb, name, color });
+    }
+    Ok(entries)
+}
+
+/// Writes a Krita palette (.kpl).
+pub fn write_kpl<W: Write>(writer: W, colors: &[Color], options: &Options) -> io::Result<()> {
+    const COLUMNS: usize = 8;
+
+    let selected = options.select(colors);
+    let mut xml = String::new();
+    xml.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    xml.push('\n');
+    xml.push_str(&std::format!(
+        r#"<Colorset version="1.0" name="{}" comment="" columns="{COLUMNS}" rows="{}" readonly="false">"#,
+        xml_escape(&options.name),
+        selected.len().div_ceil(COLUMNS),
+    ));
+    xml.push('\n');
+    for (i, color) in selected.iter().enumerate() {
+        let Rgb { r, g, b } = color.rgb;
+        xml.push_str(&std::format!(
+            concat!(
+                r#"  <ColorSetEntry name="{}" id="{}" spot="false" bitdepth="U8">"#,
+                "\n",
+                r#"    <RGB r="{}" g="{}" b="{}" space="sRGB-elle-V2-srgbtrc.icc"/>"#,
+                "\n",
+                r#"    <Position row="{}" column="{}"/>"#,
+                "\n",
+                "  </ColorSetEntry>\n",
+            ),
+            xml_escape(color.name),
+            xml_escape(color.code),
+            f32::from(r) / 255.0,
+            f32::from(g) / 255.0,
+            f32::from(b) / 255.0,
+            i / COLUMNS,
+            i % COLUMNS,
+        ));
+    }
+    xml.push_str("</Colorset>\n");
+
+    let mut zip = ZipWriter::default();
+    zip.add("mimetype", b"krita/x-colorset")?;
+    zip.add("colorset.xml", xml.as_bytes())?;
+    zip.add("profiles.xml", b"<Profiles/>\n")?;
+    zip.finish(writer)
+}
+
+/// Writes a Procreate palette (.swatches).
+///
+/// Procreate palettes hold at most [`PROCREATE_MAX_SWATCHES`] colors,
+/// so an error is returned if more colors are selected (use [`write_procreate_all()`] in that case).
+pub fn write_procreate<W: Write>(writer: W, colors: &[Color], options: &Options) -> io::Result<()> {
+    let selected = options.select(colors);
+    if selected.len() > PROCREATE_MAX_SWATCHES {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "too many colors for a Procreate palette",
+        ));
+    }
+    write_procreate_palette(writer, &options.name, &selected)
+}
+
+/// Makes as many Procreate palettes (.swatches) as needed to hold all the selected colors.
+///
+/// The selection is split into chunks of [`PROCREATE_MAX_SWATCHES`] colors.
+/// If there are several palettes, they are named "{name} 1", "{name} 2", and so on
+/// (no palette is made if no color is selected).
+pub fn write_procreate_all(colors: &[Color], options: &Options) -> io::Result<Vec<Vec<u8>>> {
+    let selected = options.select(colors);
+    let chunks = selected.chunks(PROCREATE_MAX_SWATCHES);
+    let n = chunks.len();
+    chunks
+        .enumerate()
+        .map(|(i, chunk)| {
+            let name = if n == 1 {
+                options.name.clone()
+            } else {
+                std::format!("{} {}", options.name, i + 1)
+            };
+            let mut buf = Vec::new();
+            write_procreate_palette(&mut buf, &name, chunk)?;
+            Ok(buf)
+        })
+        .collect()
+}
+
+fn write_procreate_palette<W: Write>(writer: W, name: &str, colors: &[&Color]) -> io::Result<()> {
+    let mut json = std::format!(r#"[{{"name":{},"swatches":["#, json_string(name));
+    for (i, color) in colors.iter().enumerate() {
+        let (h, s, v) = hsv(color.rgb);
+        if i > 0 {
+            json.push(',');
+        }
+        json.push_str(&std::format!(
+            r#"{{"hue":{h},"saturation":{s},"brightness":{v},"alpha":1,"colorSpace":0}}"#
+        ));
+    }
+    json.push_str("]}]");
+
+    let mut zip = ZipWriter::default();
+    zip.add("Swatches.json", json.as_bytes())?;
+    zip.finish(writer)
+}
+
+fn hsv(rgb: Rgb) -> (f32, f32, f32) {
+    let r = f32::from(rgb.r) / 255.0;
+    let g = f32::from(rgb.g) / 255.0;
+    let b = f32::from(rgb.b) / 255.0;
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+
+    let h = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        (b - r) / delta + 2.0
+    } else {
+        (r - g) / delta + 4.0
+    } / 6.0;
+    let s = if max == 0.0 { 0.0 } else { delta / max };
+    (h, s, max)
+}
+
+fn xml_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn json_string(s: &str) -> String {
+    let mut escaped = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            c if c.is_control() => escaped.push_str(&std::format!("\\u{:04x}", c as u32)),
+            _ => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ALL_COLORS;
+
+    #[test]
+    fn gpl_round_trip() {
+        let mut buf = Vec::new();
+        write_gpl(&mut buf, &ALL_COLORS, &Options::default()).unwrap();
+        let entries = read_gpl(buf.as_slice()).unwrap();
+        assert_eq!(entries.len(), ALL_COLORS.len());
+        for (entry, color) in entries.iter().zip(&ALL_COLORS) {
+            assert_eq!(entry.rgb, color.rgb);
+            assert_eq!(entry.name, std::format!("{} {}", color.code, color.name));
+            assert_eq!(entry.color, Some(*color));
+        }
+    }
+
+    #[test]
+    fn kpl_entries() {
+        let mut buf = Vec::new();
+        write_kpl(&mut buf, &ALL_COLORS, &Options::default()).unwrap();
+        let files = unzip(&buf);
+        assert_eq!(files[0], ("mimetype", &b"krita/x-colorset"[..]));
+        assert_eq!(files[1].0, "colorset.xml");
+        assert_eq!(files[2].0, "profiles.xml");
+
+        let xml = core::str::from_utf8(files[1].1).unwrap();
+        let entries = xml.split("<ColorSetEntry").skip(1).collect::<Vec<_>>();
+        assert_eq!(entries.len(), ALL_COLORS.len());
+        for (entry, color) in entries.iter().zip(&ALL_COLORS) {
+            assert_eq!(attr(entry, "id"), color.code);
+            assert_eq!(attr(entry, "name"), xml_escape(color.name));
+            let component = |name| (attr(entry, name).parse::<f32>().unwrap() * 255.0).round();
+            assert_eq!(component("r"), f32::from(color.rgb.r));
+            assert_eq!(component("g"), f32::from(color.rgb.g));
+            assert_eq!(component("b"), f32::from(color.rgb.b));
+        }
+    }
+
+    #[test]
+    fn procreate_all_colors() {
+        assert!(write_procreate(Vec::new(), &ALL_COLORS, &Options::default()).is_err());
+
+        let palettes = write_procreate_all(&ALL_COLORS, &Options::default()).unwrap();
+        assert_eq!(
+            palettes.len(),
+            ALL_COLORS.len().div_ceil(PROCREATE_MAX_SWATCHES)
+        );
+
+        let mut colors = ALL_COLORS.iter();
+        for (i, palette) in palettes.iter().enumerate() {
+            let files = unzip(palette);
+            assert_eq!(files.len(), 1);
+            assert_eq!(files[0].0, "Swatches.json");
+
+            let json: serde_json::Value = serde_json::from_slice(files[0].1).unwrap();
+            assert_eq!(json[0]["name"], std::format!("Copic {}", i + 1));
+            let swatches = json[0]["swatches"].as_array().unwrap();
+            assert!(swatches.len() <= PROCREATE_MAX_SWATCHES);
+            for swatch in swatches {
+                let component = |name: &str| swatch[name].as_f64().unwrap() as f32;
+                let rgb = hsv_to_rgb(
+                    component("hue"),
+                    component("saturation"),
+                    component("brightness"),
+                );
+                assert_eq!(rgb, colors.next().unwrap().rgb);
+            }
+        }
+        assert!(colors.next().is_none());
+    }
+
+    /// Returns the `(name, data)` pairs of a ZIP archive made of stored entries.
+    fn unzip(mut zip: &[u8]) -> Vec<(&str, &[u8])> {
+        let u16_at = |b: &[u8], i: usize| usize::from(u16::from_le_bytes([b[i], b[i + 1]]));
+        let u32_at = |b: &[u8], i: usize| u32::from_le_bytes(b[i..i + 4].try_into().unwrap());
+
+        let mut files = Vec::new();
+        while u32_at(zip, 0) == 0x04034b50 {
+            assert_eq!(u16_at(zip, 8), 0, "compression method");
+            let size = u32_at(zip, 18) as usize;
+            let name_len = u16_at(zip, 26);
+            let data_start = 30 + name_len + u16_at(zip, 28);
+            let name = core::str::from_utf8(&zip[30..30 + name_len]).unwrap();
+            let data = &zip[data_start..data_start + size];
+            assert_eq!(crate::zip::crc32(data), u32_at(zip, 14));
+            files.push((name, data));
+            zip = &zip[data_start + size..];
+        }
+        files
+    }
+
+    fn attr<'a>(element: &'a str, name: &str) -> &'a str {
+        let start = element.find(&std::format!(" {name}=\"")).unwrap() + name.len() + 3;
+        let len = element[start..].find('"').unwrap();
+        &element[start..start + len]
+    }
+
+    fn hsv_to_rgb(h: f32, s: f32, v: f32) -> Rgb {
+        let f = |n: f32| {
+            let k = (n + h * 6.0) % 6.0;
+            let c = v - v * s * k.min(4.0 - k).clamp(0.0, 1.0);
+            (c * 255.0).round() as u8
+        };
+        Rgb::new(f(5.0), f(3.0), f(1.0))
+    }
+}
@@ -0,0 +1,293 @@
+use crate::{Color, Family, Group, Value, ALL_COLORS};
+use core::ops::{Bound, RangeBounds};
+
+const COLORS: &[Color] = &ALL_COLORS;
+
+/// Index range matching nothing.
+const EMPTY: (u8, u8) = (0, 0);
+
+/// Composable filter over [`ALL_COLORS`].
+///
+/// ```
+/// use copic_colors::{Family, Group, Query, Value};
+///
+/// // Blues of the S0–S2 groups with value B0 or lighter.
+/// let light_blues = Query::new()
+///     .family(Family::Blue)
+///     .group(..=Group::S2)
+///     .value(..=Value::B0);
+/// for color in light_blues.iter() {
+///     println!("{} {}", color.code, color.name);
+/// }
+///
+/// // The same query evaluated at compile time.
+/// const LIGHT_BLUES: Query = Query::new()
+///     .family(Family::Blue)
+///     .group_between(Group::S0, Group::S2)
+///     .value_between(Value::B000, Value::B0);
+/// static LIGHT_BLUE_COLORS: [&copic_colors::Color; LIGHT_BLUES.count()] = LIGHT_BLUES.collect();
+/// assert!(LIGHT_BLUE_COLORS.iter().copied().eq(light_blues.iter()));
+/// ```
+///
+/// Group and value ranges are compared by [`Group::as_digit()`] and [`Value::lightness_index()`]
+/// (as [`Group::checked_cmp()`] and [`Value::checked_cmp()`] do), so colors whose group or value is undefined
+/// (e.g., fluorescent colors and the blender) never match a range having a bound.
+/// A range bounded by [`Group::Undefined`] or [`Value::Undefined`] matches no color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Query {
+    /// Bitset of `Family` discriminants (`0` means any family).
+    families: u32,
+
+    /// Half-open range of `Group::as_digit()` (`None` means any group, including undefined ones).
+    groups: Option<(u8, u8)>,
+
+    /// Half-open range of `Value::lightness_index()` (`None` means any value, including undefined ones).
+    values: Option<(u8, u8)>,
+}
+
+impl Query {
+    /// Makes a query matching all colors.
+    pub const fn new() -> Self {
+        Self {
+            families: 0,
+            groups: None,
+            values: None,
+        }
+    }
+
+    /// Restricts the query to colors of `family`.
+    ///
+    /// If called multiple times, colors belonging to any of the given families match.
+    pub const fn family(mut self, family: Family) -> Self {
+        self.families |= 1 << family as u32;
+        self
+    }
+
+    /// Restricts the query to colors whose group is in `range`.
+    ///
+    /// If called multiple times, only colors satisfying all the ranges match.
+    pub fn group<R: RangeBounds<Group>>(mut self, range: R) -> Self {
+        self.groups = intersect(self.groups, bounds(range, Group::as_digit));
+        self
+    }
+
+    /// Restricts the query to colors whose group is in `min..=max`.
+    ///
+    /// This is a `const` counterpart of [`Query::group()`].
+    pub const fn group_between(mut self, min: Group, max: Group) -> Self {
+        self.groups = intersect(self.groups, inclusive(min.as_digit(), max.as_digit()));
+        self
+    }
+
+    /// Restricts the query to colors whose value is in `range`.
+    ///
+    /// If called multiple times, only colors satisfying all the ranges match.
+    pub fn value<R: RangeBounds<Value>>(mut self, range: R) -> Self {
+        self.values = intersect(self.values, bounds(range, Value::lightness_index));
+        self
+    }
+
+    /// Restricts the query to colors whose value is in `min..=max`.
+    ///
+    /// This is a `const` counterpart of [`Query::value()`].
+    pub const fn value_between(mut self, min: Value, max: Value) -> Self {
+        self.values = intersect(
+            self.values,
+            inclusive(min.lightness_index(), max.lightness_index()),
+        );
+        self
+    }
+
+    /// Returns `true` if `color` satisfies this query.
+    pub const fn matches(&self, color: &Color) -> bool {
+        (self.families == 0 || self.families & (1 << color.family as u32) != 0)
+            && in_range(color.group.as_digit(), self.groups)
+            && in_range(color.value.lightness_index(), self.values)
+    }
+
+    /// Returns the colors in [`ALL_COLORS`] satisfying this query, in catalogue order.
+    pub fn iter(&self) -> QueryIter {
+        QueryIter {
+            query: *self,
+            colors: COLORS.iter(),
+        }
+    }
+
+    /// Returns the number of colors in [`ALL_COLORS`] satisfying this query.
+    pub const fn count(&self) -> usize {
+        let mut n = 0;
+        let mut i = 0;
+        while i < COLORS.len() {
+            if self.matches(&COLORS[i]) {
+                n += 1;
+            }
+            i += 1;
+        }
+        n
+    }
+
+    /// Returns the colors in [`ALL_COLORS`] satisfying this query as an array, in catalogue order.
+    ///
+    /// This is mainly intended for building static tables at compile time (see the example of [`Query`]).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` differs from [`Query::count()`].
+    pub const fn collect<const N: usize>(&self) -> [&'static Color; N] {
+        let mut colors = [&COLORS[0]; N];
+        let mut n = 0;
+        let mut i = 0;
+        while i < COLORS.len() {
+            if self.matches(&COLORS[i]) {
+                assert!(n < N, "too small array for the query result");
+                colors[n] = &COLORS[i];
+                n += 1;
+            }
+            i += 1;
+        }
+        assert!(n == N, "too large array for the query result");
+        colors
+    }
+}
+
+impl Default for Query {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl IntoIterator for Query {
+    type Item = &'static Color;
+    type IntoIter = QueryIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the colors satisfying a [`Query`], returned by [`Query::iter()`].
+#[derive(Debug, Clone)]
+pub struct QueryIter {
+    query: Query,
+    colors: core::slice::Iter<'static, Color>,
+}
+
+impl Iterator for QueryIter {
+    type Item = &'static Color;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let query = self.query;
+        self.colors.find(|c| query.matches(c))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.colors.size_hint().1)
+    }
+}
+
+impl DoubleEndedIterator for QueryIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let query = self.query;
+        self.colors.rfind(|c| query.matches(c))
+    }
+}
+
+impl core::iter::FusedIterator for QueryIter {}
+
+/// Converts `range` into a half-open range of indices (`None` if `range` is unbounded).
+fn bounds<T: Copy, R: RangeBounds<T>>(range: R, index: fn(T) -> Option<u8>) -> Option<(u8, u8)> {
+    let start = match range.start_bound() {
+        Bound::Included(&x) => index(x),
+        Bound::Excluded(&x) => index(x).map(|i| i + 1),
+        Bound::Unbounded => Some(0),
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&x) => index(x).map(|i| i + 1),
+        Bound::Excluded(&x) => index(x),
+        Bound::Unbounded => Some(u8::MAX),
+    };
+    match (range.start_bound(), range.end_bound()) {
+        (Bound::Unbounded, Bound::Unbounded) => None,
+        _ => Some(start.zip(end).unwrap_or(EMPTY)),
+    }
+}
+
+const fn inclusive(min: Option<u8>, max: Option<u8>) -> Option<(u8, u8)> {
+    match (min, max) {
+        (Some(min), Some(max)) => Some((min, max + 1)),
+        _ => Some(EMPTY),
+    }
+}
+
+const fn intersect(a: Option<(u8, u8)>, b: Option<(u8, u8)>) -> Option<(u8, u8)> {
+    match (a, b) {
+        (Some(a), Some(b)) => {
+            let start = if a.0 > b.0 { a.0 } else { b.0 };
+            let end = if a.1 < b.1 { a.1 } else { b.1 };
+            Some((start, end))
+        }
+        (Some(x), None) | (None, Some(x)) => Some(x),
+        (None, None) => None,
+    }
+}
+
+const fn in_range(index: Option<u8>, range: Option<(u8, u8)>) -> bool {
+    match (index, range) {
+        (_, None) => true,
+        (Some(i), Some((start, end))) => start <= i && i < end,
+        (None, Some(_)) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounded_ranges_exclude_undefined() {
+        let query = Query::new().value(..=Value::B0);
+        assert!(query.iter().all(|c| c.value != Value::Undefined));
+        assert_eq!(
+            query.iter().count(),
+            ALL_COLORS
+                .iter()
+                .filter(|c| c.value.checked_cmp(Value::B0).is_some_and(|o| o.is_le()))
+                .count()
+        );
+
+        let query = Query::new().group(..=Group::S0);
+        assert!(query.iter().all(|c| c.group == Group::S0));
+        assert_eq!(
+            query.count(),
+            ALL_COLORS.iter().filter(|c| c.group == Group::S0).count()
+        );
+
+        assert_eq!(Query::new().group(Group::Undefined..).count(), 0);
+        assert_eq!(
+            Query::new()
+                .value_between(Value::Undefined, Value::B9)
+                .count(),
+            0
+        );
+    }
+
+    #[test]
+    fn unbounded_ranges_match_everything() {
+        let query = Query::new().group(..).value(..);
+        assert_eq!(query.count(), ALL_COLORS.len());
+        assert!(query.iter().eq(ALL_COLORS.iter()));
+    }
+
+    #[test]
+    fn const_and_range_forms_agree() {
+        let a = Query::new()
+            .group(Group::S1..Group::S3)
+            .value(Value::B00..=Value::B2);
+        let b = Query::new()
+            .group_between(Group::S1, Group::S2)
+            .value_between(Value::B00, Value::B2);
+        assert!(a.iter().eq(b.iter()));
+        assert!(a.iter().rev().eq(b.iter().rev()));
+        assert!(a.count() > 0);
+    }
+}
@@ -0,0 +1,240 @@
+use crate::Rgb;
+use core::fmt;
+
+/// Formats as `#rrggbb`.
+impl fmt::Display for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// Formats as `rrggbb` (or `#rrggbb` with the `#` flag).
+impl fmt::LowerHex for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "#")?;
+        }
+        write!(f, "{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+/// Formats as `RRGGBB` (or `#RRGGBB` with the `#` flag).
+impl fmt::UpperHex for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "#")?;
+        }
+        write!(f, "{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+    }
+}
+
+/// Parses `#rgb`, `#rrggbb`, `rgb(r, g, b)` and `rgb(r g b / a)` notations.
+///
+/// The alpha component is validated but discarded, as [`Rgb`] has no alpha channel.
+impl core::str::FromStr for Rgb {
+    type Err = ParseRgbError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseRgbError::Empty);
+        }
+        if let Some(hex) = s.strip_prefix('#') {
+            return parse_hex(hex);
+        }
+
+        let args = s
+            .get(..4)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("rgb("))
+            .and_then(|_| s[4..].strip_suffix(')'))
+            .ok_or(ParseRgbError::InvalidSyntax)?;
+        if args.contains(',') {
+            parse_legacy_args(args)
+        } else {
+            parse_modern_args(args)
+        }
+    }
+}
+
+/// Error returned when parsing an [`Rgb`] fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParseRgbError {
+    /// The input string is empty.
+    Empty,
+
+    /// The input string is neither a `#` hex notation nor a well-formed `rgb()` function.
+    InvalidSyntax,
+
+    /// The hex notation does not have 3 or 6 digits.
+    InvalidHexLength,
+
+    /// The hex notation contains a non-hexadecimal character.
+    InvalidHexDigit,
+
+    /// The `rgb()` function does not have exactly three color components.
+    InvalidComponentCount,
+
+    /// A color component is not an integer in the range `0..=255`.
+    InvalidComponent,
+
+    /// The alpha component is not a number in the range `0..=1` (or `0%..=100%`).
+    InvalidAlpha,
+}
+
+impl fmt::Display for ParseRgbError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty RGB string"),
+            Self::InvalidSyntax => write!(f, "expected `#rrggbb`, `#rgb` or `rgb(...)`"),
+            Self::InvalidHexLength => write!(f, "hex color must have 3 or 6 digits"),
+            Self::InvalidHexDigit => write!(f, "invalid hex digit"),
+            Self::InvalidComponentCount => write!(f, "rgb() requires exactly three components"),
+            Self::InvalidComponent => write!(f, "color component must be an integer in 0..=255"),
+            Self::InvalidAlpha => write!(f, "alpha must be a number in 0..=1 or 0%..=100%"),
+        }
+    }
+}
+
+impl core::error::Error for ParseRgbError {}
+
+fn parse_hex(hex: &str) -> Result<Rgb, ParseRgbError> {
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(ParseRgbError::InvalidHexDigit);
+    }
+    let mut digits = [0; 6];
+    for (d, c) in digits.iter_mut().zip(hex.chars()) {
+        *d = c.to_digit(16).unwrap_or_default() as u8;
+    }
+    match hex.len() {
+        3 => Ok(Rgb::new(digits[0] * 17, digits[1] * 17, digits[2] * 17)),
+        6 => Ok(Rgb::new(
+            digits[0] * 16 + digits[1],
+            digits[2] * 16 + digits[3],
+            digits[4] * 16 + digits[5],
+        )),
+        _ => Err(ParseRgbError::InvalidHexLength),
+    }
+}
+
+fn parse_legacy_args(args: &str) -> Result<Rgb, ParseRgbError> {
+    if args.split(',').any(|item| item.trim().is_empty()) {
+        return Err(ParseRgbError::InvalidSyntax);
+    }
+    let mut items = args.split(',').map(str::trim);
+    let rgb = parse_components(&mut items)?;
+    if let Some(alpha) = items.next() {
+        parse_alpha(alpha)?;
+    }
+    if items.next().is_some() {
+        return Err(ParseRgbError::InvalidComponentCount);
+    }
+    Ok(rgb)
+}
+
+fn parse_modern_args(args: &str) -> Result<Rgb, ParseRgbError> {
+    let (components, alpha) = match args.split_once('/') {
+        Some((_, alpha)) if alpha.trim().is_empty() => return Err(ParseRgbError::InvalidSyntax),
+        Some((components, alpha)) => (components, Some(alpha.trim())),
+        None => (args, None),
+    };
+    let mut items = components.split_ascii_whitespace();
+    let rgb = parse_components(&mut items)?;
+    if items.next().is_some() {
+        return Err(ParseRgbError::InvalidComponentCount);
+    }
+    if let Some(alpha) = alpha {
+        parse_alpha(alpha)?;
+    }
+    Ok(rgb)
+}
+
+fn parse_components<'a>(items: &mut impl Iterator<Item = &'a str>) -> Result<Rgb, ParseRgbError> {
+    let mut next = || {
+        items
+            .next()
+            .ok_or(ParseRgbError::InvalidComponentCount)?
+            .parse::<u8>()
+            .map_err(|_| ParseRgbError::InvalidComponent)
+    };
+    Ok(Rgb::new(next()?, next()?, next()?))
+}
+
+fn parse_alpha(alpha: &str) -> Result<f32, ParseRgbError> {
+    let (number, scale) = match alpha.strip_suffix('%') {
+        Some(number) => (number, 100.0),
+        None => (alpha, 1.0),
+    };
+    let alpha = number
+        .parse::<f32>()
+        .map_err(|_| ParseRgbError::InvalidAlpha)?
+        / scale;
+    if (0.0..=1.0).contains(&alpha) {
+        Ok(alpha)
+    } else {
+        Err(ParseRgbError::InvalidAlpha)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_hex_notation() {
+        assert_eq!("#1af".parse(), Ok(Rgb::new(0x11, 0xaa, 0xff)));
+        assert_eq!("#1A2b3C".parse(), Ok(Rgb::new(0x1a, 0x2b, 0x3c)));
+        assert_eq!(" #ffffff ".parse(), Ok(Rgb::new(255, 255, 255)));
+    }
+
+    #[test]
+    fn parse_rgb_function() {
+        let rgb = Rgb::new(1, 2, 3);
+        assert_eq!("rgb(1, 2, 3)".parse(), Ok(rgb));
+        assert_eq!("RGB(1,2,3)".parse(), Ok(rgb));
+        assert_eq!("rgb(1 2 3)".parse(), Ok(rgb));
+        assert_eq!("rgb( 1  2  3 )".parse(), Ok(rgb));
+    }
+
+    #[test]
+    fn parse_with_alpha() {
+        let rgb = Rgb::new(1, 2, 3);
+        assert_eq!("rgb(1, 2, 3, 0.5)".parse(), Ok(rgb));
+        assert_eq!("rgb(1, 2, 3, 50%)".parse(), Ok(rgb));
+        assert_eq!("rgb(1 2 3 / 1)".parse(), Ok(rgb));
+        assert_eq!("rgb(1 2 3 / 0%)".parse(), Ok(rgb));
+    }
+
+    #[test]
+    fn parse_errors() {
+        let parse = |s: &str| s.parse::<Rgb>();
+        assert_eq!(parse(""), Err(ParseRgbError::Empty));
+        assert_eq!(parse("  "), Err(ParseRgbError::Empty));
+        assert_eq!(parse("1af"), Err(ParseRgbError::InvalidSyntax));
+        assert_eq!(parse("rgb(1, 2, 3"), Err(ParseRgbError::InvalidSyntax));
+        assert_eq!(parse("rgb(1, 2, 3,)"), Err(ParseRgbError::InvalidSyntax));
+        assert_eq!(parse("rgb(1 2 3 /)"), Err(ParseRgbError::InvalidSyntax));
+        assert_eq!(parse("#1a"), Err(ParseRgbError::InvalidHexLength));
+        assert_eq!(parse("#1a2b3c4d"), Err(ParseRgbError::InvalidHexLength));
+        assert_eq!(parse("#1g2"), Err(ParseRgbError::InvalidHexDigit));
+        assert_eq!(
+            parse("rgb(1, 2)"),
+            Err(ParseRgbError::InvalidComponentCount)
+        );
+        assert_eq!(
+            parse("rgb(1 2 3 4)"),
+            Err(ParseRgbError::InvalidComponentCount)
+        );
+        assert_eq!(
+            parse("rgb(1, 2, 3, 0.5, 1)"),
+            Err(ParseRgbError::InvalidComponentCount)
+        );
+        assert_eq!(
+            parse("rgb(1, 2, 256)"),
+            Err(ParseRgbError::InvalidComponent)
+        );
+        assert_eq!(parse("rgb(1 2 -3)"), Err(ParseRgbError::InvalidComponent));
+        assert_eq!(parse("rgb(1, 2, 3, 1.5)"), Err(ParseRgbError::InvalidAlpha));
+        assert_eq!(parse("rgb(1 2 3 / 101%)"), Err(ParseRgbError::InvalidAlpha));
+        assert_eq!(parse("rgb(1 2 3 / x)"), Err(ParseRgbError::InvalidAlpha));
+    }
+}
@@ -0,0 +1,250 @@
+use crate::{Color, ALL_COLORS};
+
+/// Maximum length (in bytes) of a word that can be matched by [`MatchKind::Fuzzy`].
+const MAX_FUZZY_WORD_LEN: usize = 32;
+
+/// A color found by [`search()`] together with how well it matched the query.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SearchHit<'a> {
+    pub color: &'a Color,
+
+    pub kind: MatchKind,
+
+    /// Relevance of this hit (higher is better).
+    ///
+    /// Scores are only meaningful relative to other hits of the same query.
+    pub score: u32,
+}
+
+/// How a [`SearchHit`] matched the query, from the strongest to the weakest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub enum MatchKind {
+    /// The query is the code of the color (e.g., "bv04", "C-3", "c3").
+    Code,
+
+    /// The query is the name of the color, ignoring case (e.g., "blue berry").
+    Name,
+
+    /// The name or the code starts with the query (e.g., "blue b", "bv0").
+    Prefix,
+
+    /// The name or the code contains the query (e.g., "berry").
+    Substring,
+
+    /// Each word of the query is a prefix of some word in the name (e.g., "pink shad").
+    Words,
+
+    /// Each word of the query is within a small edit distance of some word in the name (e.g., "shaddow pnik").
+    ///
+    /// `edits` is the total number of insertions, deletions, substitutions and
+    /// transpositions of adjacent characters.
+    Fuzzy { edits: u32 },
+}
+
+/// Searches [`ALL_COLORS`] by name and code, storing the best hits in `hits`.
+///
+/// See [`search_in()`] for the details.
+pub fn search(query: &str, hits: &mut [Option<SearchHit<'static>>]) -> usize {
+    let colors: &'static [Color] = &ALL_COLORS;
+    search_in(query, colors, hits)
+}
+
+/// Searches `candidates` by name and code, storing the best hits in `hits`.
+///
+/// Matching is case-insensitive and tries each [`MatchKind`] in order, so that
+/// e.g. "rv32", "Shadow Pink", "pink shad" and "shaddow pnik" all find "RV32 Shadow Pink"
+/// (with decreasing scores).
+/// Hits are sorted from the best to the worst (ties are kept in the order of `candidates`),
+/// and only the first `hits.len()` of them are kept.
+///
+/// Returns the number of hits stored; the trailing elements of `hits` are set to `None`.
+pub fn search_in<'a, I>(query: &str, candidates: I, hits: &mut [Option<SearchHit<'a>>]) -> usize
+where
+    I: IntoIterator<Item = &'a Color>,
+{
+    hits.fill(None);
+    let query = query.trim();
+    if query.is_empty() {
+        return 0;
+    }
+
+    let mut count = 0;
+    for color in candidates {
+        let Some(mut hit) = SearchHit::new(query, color) else {
+            continue;
+        };
+        for slot in hits.iter_mut() {
+            match slot {
+                Some(x) if x.score >= hit.score => {}
+                Some(x) => core::mem::swap(x, &mut hit),
+                None => {
+                    *slot = Some(hit);
+                    count += 1;
+                    break;
+                }
+            }
+        }
+    }
+    count
+}
+
+impl<'a> SearchHit<'a> {
+    fn new(query: &str, color: &'a Color) -> Option<Self> {
+        let (kind, score) = if crate::code_eq(color.code, query) {
+            (MatchKind::Code, 1000)
+        } else if color.name.eq_ignore_ascii_case(query) {
+            (MatchKind::Name, 900)
+        } else if starts_with_ignore_case(color.name, query)
+            || starts_with_ignore_case(color.code, query)
+        {
+            (MatchKind::Prefix, 800)
+        } else if let Some(score) =
+            substring_score(color.name, query).max(substring_score(color.code, query))
+        {
+            (MatchKind::Substring, score)
+        } else if words(query).all(|q| words(color.name).any(|w| starts_with_ignore_case(w, q))) {
+            (MatchKind::Words, 600)
+        } else {
+            let edits = fuzzy_edits(query, color.name)?;
+            (
+                MatchKind::Fuzzy { edits },
+                500u32.saturating_sub(edits * 50),
+            )
+        };
+        Some(Self { color, kind, score })
+    }
+}
+
+/// Returns the total edit distance needed to match every word of `query` with some word of `name`,
+/// or `None` if any word is too far from all the words of `name`.
+fn fuzzy_edits(query: &str, name: &str) -> Option<u32> {
+    words(query).try_fold(0, |total, q| {
+        let max_edits = match q.len() {
+            0..=2 => 0,
+            3..=5 => 1,
+            _ => 2,
+        };
+        words(name)
+            .filter_map(|w| edit_distance(q, w))
+            .filter(|&d| d <= max_edits)
+            .min()
+            .map(|d| total + d)
+    })
+}
+
+/// Optimal string alignment distance between `a` and `b` (ASCII case-insensitive).
+///
+/// Returns `None` if either string is longer than [`MAX_FUZZY_WORD_LEN`] bytes.
+fn edit_distance(a: &str, b: &str) -> Option<u32> {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() > MAX_FUZZY_WORD_LEN || b.len() > MAX_FUZZY_WORD_LEN {
+        return None;
+    }
+
+    // Three rolling rows of the DP table.
+    let mut prev2 = [0u32; MAX_FUZZY_WORD_LEN + 1];
+    let mut prev = [0u32; MAX_FUZZY_WORD_LEN + 1];
+    let mut curr = [0u32; MAX_FUZZY_WORD_LEN + 1];
+    for (j, x) in prev.iter_mut().enumerate().take(b.len() + 1) {
+        *x = j as u32;
+    }
+    for i in 1..=a.len() {
+        curr[0] = i as u32;
+        for j in 1..=b.len() {
+            let cost = u32::from(!a[i - 1].eq_ignore_ascii_case(&b[j - 1]));
+            curr[j] = (prev[j] + 1).min(curr[j - 1] + 1).min(prev[j - 1] + cost);
+            if i > 1
+                && j > 1
+                && a[i - 1].eq_ignore_ascii_case(&b[j - 2])
+                && a[i - 2].eq_ignore_ascii_case(&b[j - 1])
+            {
+                curr[j] = curr[j].min(prev2[j - 2] + 1);
+            }
+        }
+        prev2 = prev;
+        prev = curr;
+    }
+    Some(prev[b.len()])
+}
+
+fn words(s: &str) -> impl Iterator<Item = &str> {
+    s.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+}
+
+fn starts_with_ignore_case(s: &str, prefix: &str) -> bool {
+    s.as_bytes()
+        .get(..prefix.len())
+        .is_some_and(|head| head.eq_ignore_ascii_case(prefix.as_bytes()))
+}
+
+/// Returns the score of the best occurrence of `needle` in `haystack`, if any.
+///
+/// Occurrences at the start of a word (e.g., "berry" in "Blue Berry") are preferred to those in the middle of a word
+/// (e.g., "berry" in "Raspberry"), and earlier occurrences are preferred to later ones.
+fn substring_score(haystack: &str, needle: &str) -> Option<u32> {
+    let bytes = haystack.as_bytes();
+    bytes
+        .windows(needle.len())
+        .enumerate()
+        .filter(|(_, w)| w.eq_ignore_ascii_case(needle.as_bytes()))
+        .map(|(i, _)| {
+            let word_start = i == 0 || !bytes[i - 1].is_ascii_alphanumeric();
+            let base = if word_start { 700 } else { 650 };
+            base - i.min(49) as u32
+        })
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn best(query: &str) -> SearchHit<'static> {
+        let mut hits = [None];
+        search(query, &mut hits);
+        hits[0].unwrap_or_else(|| panic!("no hit for {query:?}"))
+    }
+
+    #[test]
+    fn examples() {
+        let hits = ["rv32", "Shadow Pink", "pink shad", "shaddow pnik"].map(best);
+        let kinds = [
+            MatchKind::Code,
+            MatchKind::Name,
+            MatchKind::Words,
+            MatchKind::Fuzzy { edits: 2 },
+        ];
+        for (hit, kind) in hits.iter().zip(kinds) {
+            assert_eq!(hit.color.code, "RV32");
+            assert_eq!(hit.kind, kind);
+        }
+        assert!(hits.windows(2).all(|w| w[0].score > w[1].score));
+    }
+
+    #[test]
+    fn code_variants() {
+        assert_eq!(best("bv04").color.code, "BV04");
+        assert_eq!(best("c3").color.code, "C-3");
+        assert_eq!(best("bv0").kind, MatchKind::Prefix);
+    }
+
+    #[test]
+    fn word_start_substring_is_preferred() {
+        let mut hits = [None; 8];
+        let n = search("berry", &mut hits);
+        let codes = hits[..n].iter().map(|h| h.unwrap().color.code);
+        let position = |code| codes.clone().position(|c| c == code).unwrap();
+        assert!(position("BV04") < position("RV66"));
+    }
+
+    #[test]
+    fn no_hits() {
+        let mut hits = [None; 4];
+        assert_eq!(search("", &mut hits), 0);
+        assert_eq!(search("zzzzzzzz", &mut hits), 0);
+        assert!(hits.iter().all(Option::is_none));
+    }
+}
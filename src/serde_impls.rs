@@ -0,0 +1,160 @@
+use crate::{Color, ColorId, ColorSet, CopicCode, Rgb};
+use core::fmt;
+use serde::de::{self, Deserialize, Deserializer, IgnoredAny, MapAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+impl Serialize for Rgb {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        crate::serde_rgb::array::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Rgb {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(crate::serde_rgb::RgbVisitor)
+    }
+}
+
+/// Deserializes a [`Color`] from either its code (e.g., `"BV04"`) or a map having a `"code"` entry
+/// (other entries are ignored), resolving it to the corresponding entry of [`ALL_COLORS`](crate::ALL_COLORS).
+impl<'de> Deserialize<'de> for Color {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ColorVisitor)
+    }
+}
+
+struct ColorVisitor;
+
+impl<'de> Visitor<'de> for ColorVisitor {
+    type Value = Color;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a Copic color code or a map containing a \"code\" entry")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut color = None;
+        while let Some(key) = map.next_key::<CodeKey>()? {
+            match key {
+                CodeKey::Code if color.is_some() => {
+                    return Err(de::Error::duplicate_field("code"));
+                }
+                CodeKey::Code => color = Some(map.next_value_seed(ColorSeed)?),
+                CodeKey::Other => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        color.ok_or_else(|| de::Error::missing_field("code"))
+    }
+}
+
+struct ColorSeed;
+
+impl<'de> de::DeserializeSeed<'de> for ColorSeed {
+    type Value = Color;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_str(ColorVisitor)
+    }
+}
+
+enum CodeKey {
+    Code,
+    Other,
+}
+
+impl<'de> Deserialize<'de> for CodeKey {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_identifier(CodeKeyVisitor)
+    }
+}
+
+struct CodeKeyVisitor;
+
+impl Visitor<'_> for CodeKeyVisitor {
+    type Value = CodeKey;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a field name")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(if v == "code" {
+            CodeKey::Code
+        } else {
+            CodeKey::Other
+        })
+    }
+}
+
+impl Serialize for CopicCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CopicCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(CopicCodeVisitor)
+    }
+}
+
+struct CopicCodeVisitor;
+
+impl Visitor<'_> for CopicCodeVisitor {
+    type Value = CopicCode;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a Copic color code")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+/// Serializes a [`ColorId`] as its numeric value.
+impl Serialize for ColorId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u16(self.as_u16())
+    }
+}
+
+impl<'de> Deserialize<'de> for ColorId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let id = u16::deserialize(deserializer)?;
+        Self::try_from(id).map_err(de::Error::custom)
+    }
+}
+
+/// Serializes a [`ColorSet`] in its compact text encoding.
+impl Serialize for ColorSet {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ColorSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(ColorSetVisitor)
+    }
+}
+
+struct ColorSetVisitor;
+
+impl Visitor<'_> for ColorSetVisitor {
+    type Value = ColorSet;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a hex-encoded color set")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+}
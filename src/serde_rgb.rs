@@ -0,0 +1,74 @@
+//! Alternative serde representations of [`Rgb`].
+//!
+//! By default, [`Rgb`] is serialized as an `[r, g, b]` array.
+//! Use these modules with `#[serde(with = "...")]` to choose the representation of a field explicitly:
+//!
+//! ```
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Swatch {
+//!     #[serde(with = "copic_colors::serde_rgb::hex")]
+//!     rgb: copic_colors::Rgb,
+//! }
+//! ```
+//!
+//! Regardless of the chosen representation, deserialization accepts both forms.
+use crate::Rgb;
+use core::fmt;
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::ser::{SerializeTuple, Serializer};
+
+/// Serializes [`Rgb`] as an `[r, g, b]` array.
+pub mod array {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(rgb: &Rgb, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(3)?;
+        tuple.serialize_element(&rgb.r)?;
+        tuple.serialize_element(&rgb.g)?;
+        tuple.serialize_element(&rgb.b)?;
+        tuple.end()
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Rgb, D::Error> {
+        deserializer.deserialize_any(RgbVisitor)
+    }
+}
+
+/// Serializes [`Rgb`] as a `"#rrggbb"` string.
+pub mod hex {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(rgb: &Rgb, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(rgb)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Rgb, D::Error> {
+        deserializer.deserialize_any(RgbVisitor)
+    }
+}
+
+pub(crate) struct RgbVisitor;
+
+impl<'de> Visitor<'de> for RgbVisitor {
+    type Value = Rgb;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an [r, g, b] array or an RGB string such as \"#rrggbb\"")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut next = |i| {
+            seq.next_element::<u8>()?
+                .ok_or_else(|| de::Error::invalid_length(i, &self))
+        };
+        let rgb = Rgb::new(next(0)?, next(1)?, next(2)?);
+        if seq.next_element::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(4, &self));
+        }
+        Ok(rgb)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+}
@@ -0,0 +1,119 @@
+//! Minimal ZIP archive writer (stored entries only).
+use std::io::{self, Write};
+use std::vec::Vec;
+
+/// ZIP archive whose entries are stored without compression.
+#[derive(Debug, Default)]
+pub(crate) struct ZipWriter {
+    buf: Vec<u8>,
+    central_directory: Vec<u8>,
+    entries: u16,
+}
+
+impl ZipWriter {
+    pub fn add(&mut self, name: &str, data: &[u8]) -> io::Result<()> {
+        let offset = u32_len(self.buf.len())?;
+        let size = u32_len(data.len())?;
+        let name_len =
+            u16::try_from(name.len()).map_err(|_| invalid_input("too long file name"))?;
+        let crc = crc32(data);
+
+        // Local file header.
+        self.buf.extend_from_slice(&0x04034b50u32.to_le_bytes());
+        self.buf.extend_from_slice(&20u16.to_le_bytes()); // version needed to extract
+        self.buf.extend_from_slice(&0u16.to_le_bytes()); // flags
+        self.buf.extend_from_slice(&0u16.to_le_bytes()); // compression method (stored)
+        self.buf.extend_from_slice(&0u16.to_le_bytes()); // modification time
+        self.buf.extend_from_slice(&DOS_EPOCH_DATE.to_le_bytes());
+        self.buf.extend_from_slice(&crc.to_le_bytes());
+        self.buf.extend_from_slice(&size.to_le_bytes()); // compressed size
+        self.buf.extend_from_slice(&size.to_le_bytes()); // uncompressed size
+        self.buf.extend_from_slice(&name_len.to_le_bytes());
+        self.buf.extend_from_slice(&0u16.to_le_bytes()); // extra field length
+        self.buf.extend_from_slice(name.as_bytes());
+        self.buf.extend_from_slice(data);
+
+        // Central directory file header.
+        let cd = &mut self.central_directory;
+        cd.extend_from_slice(&0x02014b50u32.to_le_bytes());
+        cd.extend_from_slice(&20u16.to_le_bytes()); // version made by
+        cd.extend_from_slice(&20u16.to_le_bytes()); // version needed to extract
+        cd.extend_from_slice(&0u16.to_le_bytes()); // flags
+        cd.extend_from_slice(&0u16.to_le_bytes()); // compression method (stored)
+        cd.extend_from_slice(&0u16.to_le_bytes()); // modification time
+        cd.extend_from_slice(&DOS_EPOCH_DATE.to_le_bytes());
+        cd.extend_from_slice(&crc.to_le_bytes());
+        cd.extend_from_slice(&size.to_le_bytes()); // compressed size
+        cd.extend_from_slice(&size.to_le_bytes()); // uncompressed size
+        cd.extend_from_slice(&name_len.to_le_bytes());
+        cd.extend_from_slice(&0u16.to_le_bytes()); // extra field length
+        cd.extend_from_slice(&0u16.to_le_bytes()); // file comment length
+        cd.extend_from_slice(&0u16.to_le_bytes()); // disk number start
+        cd.extend_from_slice(&0u16.to_le_bytes()); // internal file attributes
+        cd.extend_from_slice(&0u32.to_le_bytes()); // external file attributes
+        cd.extend_from_slice(&offset.to_le_bytes());
+        cd.extend_from_slice(name.as_bytes());
+
+        self.entries = self
+            .entries
+            .checked_add(1)
+            .ok_or_else(|| invalid_input("too many ZIP entries"))?;
+        Ok(())
+    }
+
+    pub fn finish<W: Write>(self, mut writer: W) -> io::Result<()> {
+        let cd_offset = u32_len(self.buf.len())?;
+        let cd_size = u32_len(self.central_directory.len())?;
+        writer.write_all(&self.buf)?;
+        writer.write_all(&self.central_directory)?;
+
+        // End of central directory record.
+        writer.write_all(&0x06054b50u32.to_le_bytes())?;
+        writer.write_all(&0u16.to_le_bytes())?; // number of this disk
+        writer.write_all(&0u16.to_le_bytes())?; // disk where central directory starts
+        writer.write_all(&self.entries.to_le_bytes())?; // entries on this disk
+        writer.write_all(&self.entries.to_le_bytes())?; // total entries
+        writer.write_all(&cd_size.to_le_bytes())?;
+        writer.write_all(&cd_offset.to_le_bytes())?;
+        writer.write_all(&0u16.to_le_bytes()) // comment length
+    }
+}
+
+/// 1980-01-01 in the MS-DOS date format.
+const DOS_EPOCH_DATE: u16 = (1 << 5) | 1;
+
+fn u32_len(len: usize) -> io::Result<u32> {
+    u32::try_from(len).map_err(|_| invalid_input("too large ZIP archive"))
+}
+
+fn invalid_input(message: &'static str) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidInput, message)
+}
+
+static CRC32_TABLE: [u32; 256] = crc32_table();
+
+const fn crc32_table() -> [u32; 256] {
+    let mut table = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut c = i as u32;
+        let mut k = 0;
+        while k < 8 {
+            c = if c & 1 != 0 {
+                0xEDB88320 ^ (c >> 1)
+            } else {
+                c >> 1
+            };
+            k += 1;
+        }
+        table[i] = c;
+        i += 1;
+    }
+    table
+}
+
+pub(crate) fn crc32(data: &[u8]) -> u32 {
+    !data.iter().fold(!0, |crc, &b| {
+        CRC32_TABLE[usize::from((crc as u8) ^ b)] ^ (crc >> 8)
+    })
+}